@@ -5,7 +5,7 @@ use promptis::Input;
 fn main() {
     let choice = Input::new().quit("quit").wait_opts(
         &[Choice::Yes, Choice::No],
-        &format!("Do you want to hear the dog speak?\nYour choice: "),
+        "Do you want to hear the dog speak?\nYour choice: ",
     );
 
     match choice {
@@ -1,14 +1,9 @@
 use promptis::Input;
 
 fn main() {
-    let keyword = "quit";
-    println!(
-        "Enter the phrase '{}' to close the application early!",
-        keyword
-    );
-
     let _ = Input::new()
-        .quit(keyword)
+        .quit("quit")
+        .show_quit_hint(true)
         .prompt("Enter: ")
         .wait::<String>();
     println!("Exiting normally - goodbye!");
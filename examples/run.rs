@@ -5,6 +5,7 @@ fn main() {
     let mut data = Vec::new();
     let mut input = Input::new()
         .quit("quit")
+        .lenient_numbers(true)
         .err_msg("Unexpected input, please retry");
     for _ in 0..num_mats {
         let mat: String = input.prompt("Material ID: ").wait();
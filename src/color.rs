@@ -0,0 +1,50 @@
+use std::io::{stderr, stdout, IsTerminal};
+
+/// A terminal color for prompts and messages, see [Input::color](crate::Input::color)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Wraps `text` in the ANSI escape codes for this color.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Color;
+    /// assert_eq!(Color::Red.paint("Oops"), "\x1b[31mOops\x1b[0m");
+    /// ```
+    pub fn paint(self, text: &str) -> String {
+        format!("\x1b[{}m{}\x1b[0m", self.code(), text)
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+}
+
+/// Checks whether colored output is appropriate: stdout (or with `to_stderr`, stderr)
+/// has to be a terminal, and `NO_COLOR` mustn't be set (see <https://no-color.org>)
+pub(crate) fn supported(to_stderr: bool) -> bool {
+    let terminal = match to_stderr {
+        true => stderr().is_terminal(),
+        false => stdout().is_terminal(),
+    };
+    terminal && std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+}
@@ -0,0 +1,79 @@
+use crate::Input;
+
+/// Plain data describing how an [Input] should behave, for when prompt behavior is
+/// loaded from a config file rather than set up in code.
+///
+/// Every field is public and optional (or defaults to off), so it can be filled in
+/// from whatever format the application already reads.
+///
+/// Example:
+/// ```
+/// # use promptis::{Input, InputConfig};
+/// let text = "
+///     prompt = Enter your age:\x20
+///     err_msg = Unexpected input; please retry
+/// ";
+///
+/// let mut cfg = InputConfig::default();
+/// for (key, value) in text.lines().filter_map(|l| l.trim_start().split_once(" = ")) {
+///     match key {
+///         "prompt" => cfg.prompt = Some(value.into()),
+///         "err_msg" => cfg.err_msg = Some(value.into()),
+///         _ => {}
+///     }
+/// }
+///
+/// let age: u32 = Input::from_config(cfg)
+///     .with_reader(&b"old\n42\n"[..])
+///     .wait();
+///
+/// assert_eq!(age, 42);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InputConfig {
+    /// See [Input::prompt]
+    pub prompt: Option<String>,
+    /// See [Input::quit]
+    pub quit: Option<String>,
+    /// See [Input::err_msg]
+    pub err_msg: Option<String>,
+    /// See [Input::soft_quit]
+    pub soft_quit: bool,
+    /// See [Input::menu_retry_prompt]
+    pub menu_retry_prompt: Option<String>,
+    /// See [Input::sticky_default]
+    pub sticky_default: bool,
+    /// See [Input::currency]
+    pub currency: Option<String>,
+    /// See [Input::accept_leading]
+    pub accept_leading: bool,
+    /// See [Input::color]
+    pub color: bool,
+    /// See [Input::success_msg]
+    pub success_msg: Option<String>,
+}
+
+impl Input {
+    /// Creates a new Input object configured by `cfg`.
+    pub fn from_config(cfg: InputConfig) -> Self {
+        Self {
+            user_prompt: cfg.prompt.unwrap_or_default(),
+            user_quit: cfg.quit.into_iter().collect(),
+            user_errmsg: cfg.err_msg,
+            soft_quit: cfg.soft_quit,
+            menu_retry: cfg.menu_retry_prompt,
+            sticky: cfg.sticky_default,
+            currency: cfg.currency,
+            accept_leading: cfg.accept_leading,
+            color: cfg.color,
+            success_msg: cfg.success_msg,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<InputConfig> for Input {
+    fn from(cfg: InputConfig) -> Self {
+        Self::from_config(cfg)
+    }
+}
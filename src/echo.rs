@@ -0,0 +1,250 @@
+//! Hiding typed input on a terminal
+
+use crate::interrupt::BeforeExit;
+
+/// Turns off the terminal's echo of typed characters for as long as it's alive, if stdin
+/// is a terminal. Line editing, like backspace, keeps working.
+///
+/// Echo is turned back on when it's dropped, and also if Ctrl-C ends the program first.
+pub(crate) struct NoEcho {
+    saved: Option<termios::Termios>,
+    _exit: Option<BeforeExit>,
+}
+
+impl NoEcho {
+    pub(crate) fn start() -> Self {
+        let saved = termios::echo_off();
+        Self {
+            _exit: saved.map(|_| BeforeExit::start(termios::restore_on_exit)),
+            saved,
+        }
+    }
+
+    /// Like `start`, but also turns off line buffering, so each key can be read as it's
+    /// typed (Ctrl-C still interrupts). This only happens if stdin is a terminal, and
+    /// `None` is returned otherwise.
+    pub(crate) fn by_key() -> Option<Self> {
+        termios::key_mode().map(|saved| Self {
+            _exit: Some(BeforeExit::start(termios::restore_on_exit)),
+            saved: Some(saved),
+        })
+    }
+
+    /// Whether echo was actually turned off, so the Enter ending the line went unseen
+    pub(crate) fn is_hiding(&self) -> bool {
+        self.saved.is_some()
+    }
+}
+
+impl Drop for NoEcho {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            termios::restore(saved);
+        }
+    }
+}
+
+/// The `termios` layout and flags below are the ones in the C headers of macOS and of
+/// Linux on these architectures. Others, like powerpc, mips and sparc Linux, lay it out
+/// differently, so they get the fallback further down.
+#[cfg(any(
+    target_os = "macos",
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64"
+        )
+    )
+))]
+mod termios {
+    use std::io::{stdin, IsTerminal};
+    use std::mem::size_of;
+    use std::os::raw::c_int;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[cfg(target_os = "linux")]
+    type Flag = u32;
+    #[cfg(target_os = "macos")]
+    type Flag = std::os::raw::c_ulong;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub(crate) struct Termios {
+        c_iflag: Flag,
+        c_oflag: Flag,
+        c_cflag: Flag,
+        c_lflag: Flag,
+        #[cfg(target_os = "linux")]
+        c_line: u8,
+        #[cfg(target_os = "linux")]
+        c_cc: [u8; 32],
+        #[cfg(target_os = "macos")]
+        c_cc: [u8; 20],
+        // The padding C leaves before the speeds, spelled out so copies of it are
+        // defined, for `save`
+        #[cfg(target_os = "linux")]
+        _pad: [u8; 3],
+        #[cfg(target_os = "macos")]
+        _pad: [u8; 4],
+        c_ispeed: Flag,
+        c_ospeed: Flag,
+    }
+
+    extern "C" {
+        fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+        fn tcsetattr(fd: c_int, action: c_int, termios: *const Termios) -> c_int;
+    }
+
+    const ECHO: Flag = 0o10;
+    #[cfg(target_os = "linux")]
+    const ICANON: Flag = 0o2;
+    #[cfg(target_os = "macos")]
+    const ICANON: Flag = 0x100;
+    #[cfg(target_os = "linux")]
+    const VTIME: usize = 5;
+    #[cfg(target_os = "macos")]
+    const VTIME: usize = 17;
+    #[cfg(target_os = "linux")]
+    const VMIN: usize = 6;
+    #[cfg(target_os = "macos")]
+    const VMIN: usize = 16;
+    const TCSANOW: c_int = 0;
+
+    /// The settings last changed by `change`, for `restore_on_exit`. They're kept in
+    /// atomic words rather than behind a lock, since a signal handler reads them.
+    static SAVED: [AtomicUsize; WORDS] = [const { AtomicUsize::new(0) }; WORDS];
+    /// Whether `SAVED` holds settings to put back
+    static ARMED: AtomicBool = AtomicBool::new(false);
+    const WORDS: usize = size_of::<Termios>().div_ceil(size_of::<usize>());
+
+    // The size of the C struct, which with no hidden padding the fields add up to
+    #[cfg(target_os = "linux")]
+    const _: () = assert!(size_of::<Termios>() == 60);
+    #[cfg(target_os = "macos")]
+    const _: () = assert!(size_of::<Termios>() == 72);
+
+    /// Turns echo off on stdin's terminal, returning the settings to restore
+    pub(crate) fn echo_off() -> Option<Termios> {
+        change(|t| t.c_lflag &= !ECHO)
+    }
+
+    /// Turns echo and line buffering off on stdin's terminal, so reads return each key
+    /// as it's pressed, returning the settings to restore
+    pub(crate) fn key_mode() -> Option<Termios> {
+        change(|t| {
+            t.c_lflag &= !(ECHO | ICANON);
+            t.c_cc[VMIN] = 1;
+            t.c_cc[VTIME] = 0;
+        })
+    }
+
+    /// Applies `f` to the settings of stdin's terminal, returning the ones to restore
+    fn change(f: impl FnOnce(&mut Termios)) -> Option<Termios> {
+        if !stdin().is_terminal() {
+            return None;
+        }
+
+        // Zeroed, so the padding is set even though tcgetattr leaves it alone
+        let mut saved = std::mem::MaybeUninit::<Termios>::zeroed();
+        // SAFETY: tcgetattr fills in the rest of the struct when it succeeds
+        let saved = unsafe {
+            if tcgetattr(0, saved.as_mut_ptr()) != 0 {
+                return None;
+            }
+            saved.assume_init()
+        };
+
+        let mut changed = saved;
+        f(&mut changed);
+        // SAFETY: `changed` is a valid termios, read by tcsetattr only
+        if unsafe { tcsetattr(0, TCSANOW, &changed) } != 0 {
+            return None;
+        }
+        save(&saved);
+        Some(saved)
+    }
+
+    /// Keeps `saved` in `SAVED`, for `restore_on_exit` to put back
+    fn save(saved: &Termios) {
+        ARMED.store(false, Ordering::SeqCst);
+        let mut words = [0usize; WORDS];
+        // SAFETY: `words` is at least as large as a `Termios`, which has no padding left
+        // to be uninitialized, and neither overlaps
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                saved as *const Termios as *const u8,
+                words.as_mut_ptr() as *mut u8,
+                size_of::<Termios>(),
+            );
+        }
+        for (slot, word) in SAVED.iter().zip(words) {
+            slot.store(word, Ordering::SeqCst);
+        }
+        ARMED.store(true, Ordering::SeqCst);
+    }
+
+    /// Puts back settings saved by `echo_off` or `key_mode`
+    pub(crate) fn restore(saved: &Termios) {
+        ARMED.store(false, Ordering::SeqCst);
+        // SAFETY: `saved` came from tcgetattr
+        unsafe { tcsetattr(0, TCSANOW, saved) };
+    }
+
+    /// Puts back the settings last changed, as Ctrl-C ends the program. This runs in a
+    /// signal handler, so it only reads atomics and calls `tcsetattr`, which is safe there.
+    pub(crate) fn restore_on_exit() {
+        if !ARMED.load(Ordering::SeqCst) {
+            return;
+        }
+        let words = SAVED.each_ref().map(|slot| slot.load(Ordering::SeqCst));
+        let mut saved = std::mem::MaybeUninit::<Termios>::uninit();
+        // SAFETY: the words hold a `Termios` copied in by `save`, and it's plain integers,
+        // so any bytes make a valid one
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                words.as_ptr() as *const u8,
+                saved.as_mut_ptr() as *mut u8,
+                size_of::<Termios>(),
+            );
+            tcsetattr(0, TCSANOW, saved.as_ptr());
+        }
+    }
+}
+
+/// Where the `termios` layout hasn't been checked, typed input is simply left visible
+#[cfg(not(any(
+    target_os = "macos",
+    all(
+        target_os = "linux",
+        any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm",
+            target_arch = "aarch64",
+            target_arch = "riscv64"
+        )
+    )
+)))]
+mod termios {
+    /// Never made, since echo is never turned off
+    #[derive(Clone, Copy)]
+    pub(crate) enum Termios {}
+
+    pub(crate) fn echo_off() -> Option<Termios> {
+        None
+    }
+
+    pub(crate) fn key_mode() -> Option<Termios> {
+        None
+    }
+
+    pub(crate) fn restore(saved: &Termios) {
+        match *saved {}
+    }
+
+    pub(crate) fn restore_on_exit() {}
+}
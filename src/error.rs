@@ -0,0 +1,117 @@
+use std::fmt;
+
+/// The error returned when the user didn't give acceptable input within the
+/// allowed number of attempts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyAttempts {
+    /// How many attempts were made
+    pub attempts: usize,
+}
+
+impl fmt::Display for TooManyAttempts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no acceptable input after {} attempts", self.attempts)
+    }
+}
+
+impl std::error::Error for TooManyAttempts {}
+
+/// The error returned by [Input::wait_or_quit](crate::Input::wait_or_quit) when the
+/// user entered the quit trigger
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quit;
+
+impl fmt::Display for Quit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the quit trigger was entered")
+    }
+}
+
+impl std::error::Error for Quit {}
+
+/// The ways [Input::try_wait](crate::Input::try_wait) and its relatives can fail to
+/// produce a value
+#[derive(Debug)]
+pub enum InputError {
+    /// The same invalid input was entered this many times in a row (see
+    /// [Input::abort_on_repeat](crate::Input::abort_on_repeat))
+    Repeated {
+        /// The input that kept being entered, minus its line ending
+        input: String,
+        /// How many times in a row it was entered
+        times: usize,
+    },
+    /// Invalid input was entered as many times as
+    /// [Input::max_attempts](crate::Input::max_attempts) allows
+    Exhausted {
+        /// How many invalid attempts were made
+        attempts: usize,
+    },
+    /// The quit trigger was entered, with [Input::soft_quit](crate::Input::soft_quit) set
+    Quit,
+    /// Nothing was entered within the [Input::timeout](crate::Input::timeout), and there
+    /// was no default to fall back to
+    TimedOut,
+    /// Ctrl-C was pressed, with [Input::on_interrupt](crate::Input::on_interrupt) set to
+    /// [InterruptMode::ReturnErr](crate::InterruptMode::ReturnErr)
+    Interrupted,
+    /// Input ended (e.g. piped input ran out, or Ctrl-D was pressed) before a value was
+    /// entered
+    Eof,
+    /// Reading input failed
+    Io(std::io::Error),
+    /// The input couldn't be parsed (or failed `validate`)
+    Parse {
+        /// The input, minus its line ending
+        input: String,
+    },
+    /// [Input::try_wait_opts_indexed](crate::Input::try_wait_opts_indexed) was given no
+    /// options to choose from
+    NoOptions,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Repeated { input, times } => {
+                write!(
+                    f,
+                    "the same invalid input {:?} was entered {} times in a row",
+                    input, times
+                )
+            }
+            InputError::Exhausted { attempts } => {
+                write!(f, "no valid input after {} attempts", attempts)
+            }
+            InputError::Quit => f.write_str("the quit trigger was entered"),
+            InputError::TimedOut => f.write_str("no input was entered in time"),
+            InputError::Interrupted => f.write_str("the prompt was interrupted"),
+            InputError::Eof => f.write_str("input ended before a value was entered"),
+            InputError::Io(e) => write!(f, "failed to read input: {}", e),
+            InputError::Parse { input } => write!(f, "couldn't use the input {:?}", input),
+            InputError::NoOptions => f.write_str("there were no options to choose from"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The error returned by [PromptSession::run](crate::PromptSession::run) when the user
+/// entered the quit trigger partway through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionAborted;
+
+impl fmt::Display for SessionAborted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the session was aborted with the quit trigger")
+    }
+}
+
+impl std::error::Error for SessionAborted {}
@@ -0,0 +1,112 @@
+//! Filling in the fields of an existing struct from a series of prompts.
+
+use std::fmt;
+use std::rc::Rc;
+
+use crate::{Callback, Input};
+
+/// A list of questions, each tied to a setter that stores its parsed answer in a struct
+/// of type `S`.
+///
+/// Each field has its own answer type, so one form can fill a `String` field and a
+/// `u32` one alike. Fields are asked in the order they were added, each read as by
+/// [Input::wait], so `err_msg` and the quit trigger work as usual.
+///
+/// Example:
+/// ```
+/// # use promptis::{Form, Input};
+/// use std::io::Cursor;
+///
+/// #[derive(Default)]
+/// struct Account {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let input = Input::new().chain_source(Box::new(Cursor::new("Ada\nthirty-six\n36\n")));
+/// let form = Form::new(input)
+///     .field("Name: ", |a: &mut Account, name: String| a.name = name)
+///     .field("Age: ", |a: &mut Account, age: u32| a.age = age);
+///
+/// let mut account = Account::default();
+/// form.fill(&mut account);
+///
+/// assert_eq!(account.name, "Ada");
+/// assert_eq!(account.age, 36);
+/// ```
+pub struct Form<S> {
+    input: Input,
+    fields: Vec<Field<S>>,
+}
+
+/// One question of a [Form]
+struct Field<S> {
+    prompt: String,
+    fill: Callback<FillFn<S>>,
+}
+
+/// Asks a field's prompt with the given Input and stores the answer in the struct
+type FillFn<S> = dyn Fn(&Input, &str, &mut S);
+
+impl<S> Form<S> {
+    /// Creates an empty form that asks its questions with `input`.
+    pub fn new(input: Input) -> Self {
+        Self {
+            input,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a question, shown as `prompt`, whose answer must parse to `T` and is then
+    /// handed to `set` along with the struct being filled.
+    pub fn field<T, F>(mut self, prompt: &str, set: F) -> Self
+    where
+        T: std::str::FromStr + 'static,
+        F: Fn(&mut S, T) + 'static,
+    {
+        self.fields.push(Field {
+            prompt: prompt.to_owned(),
+            fill: Callback(Rc::new(move |input: &Input, p: &str, target: &mut S| {
+                let value: T = input.wait_parsed_msg(p, |s| input.parse_valid(s));
+                set(target, value);
+            })),
+        });
+        self
+    }
+
+    /// Asks every question in order, storing each answer in `target` as soon as it's
+    /// given.
+    pub fn fill(&self, target: &mut S) {
+        for field in &self.fields {
+            (field.fill.0)(&self.input, &field.prompt, target);
+        }
+    }
+}
+
+impl<S> Clone for Form<S> {
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input.clone(),
+            fields: self.fields.clone(),
+        }
+    }
+}
+
+impl<S> Clone for Field<S> {
+    fn clone(&self) -> Self {
+        Self {
+            prompt: self.prompt.clone(),
+            fill: self.fill.clone(),
+        }
+    }
+}
+
+impl<S> fmt::Debug for Form<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prompts: Vec<&str> = self.fields.iter().map(|f| f.prompt.as_str()).collect();
+        f.debug_struct("Form")
+            .field("input", &self.input)
+            .field("fields", &prompts)
+            .finish()
+    }
+}
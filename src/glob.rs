@@ -0,0 +1,101 @@
+//! Minimal shell-style glob expansion for [Input::wait_paths](crate::Input::wait_paths).
+//!
+//! Supports `*`, `?` and bracket classes (`[abc]`, `[a-z]`, `[!a]`) within a single
+//! path component. As in a shell, wildcards don't match a leading `.`.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Returns whether `word` contains any glob wildcards
+pub(crate) fn is_pattern(word: &str) -> bool {
+    word.contains(['*', '?', '['])
+}
+
+/// Expands `pattern` into the existing paths it matches, sorted
+pub(crate) fn expand(pattern: &str) -> Vec<PathBuf> {
+    let mut found = vec![PathBuf::new()];
+
+    for comp in Path::new(pattern).components() {
+        let Component::Normal(name) = comp else {
+            found.iter_mut().for_each(|p| p.push(comp));
+            continue;
+        };
+
+        let name = name.to_string_lossy();
+        if !is_pattern(&name) {
+            found.iter_mut().for_each(|p| p.push(&*name));
+            continue;
+        }
+
+        let pat: Vec<char> = name.chars().collect();
+        found = found
+            .iter()
+            .filter_map(|base| {
+                let dir = if base.as_os_str().is_empty() {
+                    Path::new(".")
+                } else {
+                    base
+                };
+                std::fs::read_dir(dir).ok().map(|entries| (base, entries))
+            })
+            .flat_map(|(base, entries)| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter(|e| {
+                        let entry = e.file_name().to_string_lossy().chars().collect::<Vec<_>>();
+                        (entry.first() != Some(&'.') || pat.first() == Some(&'.'))
+                            && matches(&pat, &entry)
+                    })
+                    .map(|e| base.join(e.file_name()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+    }
+
+    found.retain(|p| p.exists());
+    found.sort();
+    found
+}
+
+/// Matches a single path component against a pattern
+fn matches(pat: &[char], name: &[char]) -> bool {
+    match pat.first() {
+        None => name.is_empty(),
+        Some('*') => (0..=name.len()).any(|i| matches(&pat[1..], &name[i..])),
+        Some('?') => !name.is_empty() && matches(&pat[1..], &name[1..]),
+        Some('[') => match (class(&pat[1..]), name.first()) {
+            (Some((set, rest)), Some(c)) => set(*c) && matches(rest, &name[1..]),
+            (Some(_), None) => false,
+            // An unclosed bracket is just a literal '['
+            (None, _) => name.first() == Some(&'[') && matches(&pat[1..], &name[1..]),
+        },
+        Some(c) => name.first() == Some(c) && matches(&pat[1..], &name[1..]),
+    }
+}
+
+/// Parses a bracket class (just past its `[`), returning a test for it and the
+/// rest of the pattern after its `]`
+fn class(pat: &[char]) -> Option<(impl Fn(char) -> bool + '_, &[char])> {
+    let negated = matches!(pat.first(), Some('!' | '^'));
+    let body = if negated { &pat[1..] } else { pat };
+
+    // A ']' right at the start is part of the class rather than closing it
+    let close = body.iter().skip(1).position(|c| *c == ']')? + 1;
+    let (items, rest) = (&body[..close], &body[close + 1..]);
+
+    let test = move |c: char| {
+        let mut i = 0;
+        let mut hit = false;
+        while i < items.len() {
+            if i + 2 < items.len() && items[i + 1] == '-' {
+                hit |= (items[i]..=items[i + 2]).contains(&c);
+                i += 3;
+            } else {
+                hit |= items[i] == c;
+                i += 1;
+            }
+        }
+        hit != negated
+    };
+
+    Some((test, rest))
+}
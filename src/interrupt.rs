@@ -0,0 +1,280 @@
+//! Handling Ctrl-C while waiting for input
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// What pressing Ctrl-C (SIGINT) does while an [Input](crate::Input) waits for the user,
+/// see [Input::on_interrupt](crate::Input::on_interrupt)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InterruptMode {
+    /// Whatever it did before, which unless the program changed it is ending the program
+    #[default]
+    Exit,
+    /// The read gives up: the `try_` methods return
+    /// [InputError::Interrupted](crate::InputError::Interrupted), and `wait` asks again
+    ReturnErr,
+    /// Nothing; the user keeps being asked
+    Ignore,
+}
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// What a `BeforeExit` runs when Ctrl-C ends the program: a `fn()` stored as its
+/// address, or 0 for nothing, so the signal handler can read it without a lock
+static CLEANUP: AtomicUsize = AtomicUsize::new(0);
+
+/// Handles SIGINT as `mode` says for as long as it's alive, then puts back whatever
+/// handled it before
+pub(crate) struct Catch {
+    #[cfg(unix)]
+    saved: Option<signal::Saved>,
+}
+
+impl Catch {
+    pub(crate) fn start(mode: InterruptMode) -> Self {
+        INTERRUPTED.store(false, Ordering::SeqCst);
+        #[cfg(unix)]
+        return Self {
+            saved: signal::set(mode),
+        };
+        #[cfg(not(unix))]
+        return {
+            let _ = mode;
+            Self {}
+        };
+    }
+
+    /// Whether Ctrl-C was pressed since this started, or since the last time it was asked
+    pub(crate) fn take(&self) -> bool {
+        INTERRUPTED.swap(false, Ordering::SeqCst)
+    }
+}
+
+impl Drop for Catch {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(saved) = self.saved.take() {
+            signal::restore(saved);
+        }
+    }
+}
+
+/// Makes a Ctrl-C that ends the program run `cleanup` first for as long as it's alive,
+/// e.g. to put the terminal back the way it was. If something else already handles
+/// Ctrl-C, it's left alone, since then it's in charge of what happens.
+///
+/// `cleanup` runs in the signal handler, so it must be async-signal-safe: no locks, no
+/// allocation, no printing. Anything more is for the code the read returns to.
+pub(crate) struct BeforeExit {
+    #[cfg(unix)]
+    saved: Option<signal::Saved>,
+}
+
+impl BeforeExit {
+    pub(crate) fn start(cleanup: fn()) -> Self {
+        CLEANUP.store(cleanup as usize, Ordering::SeqCst);
+        #[cfg(unix)]
+        return Self {
+            saved: signal::set_before_exit(),
+        };
+        #[cfg(not(unix))]
+        return Self {};
+    }
+}
+
+impl Drop for BeforeExit {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(saved) = self.saved.take() {
+            signal::restore(saved);
+        }
+        CLEANUP.store(0, Ordering::SeqCst);
+    }
+}
+
+#[cfg(unix)]
+mod signal {
+    use super::{InterruptMode, CLEANUP, INTERRUPTED};
+    use std::os::raw::c_int;
+    use std::sync::atomic::Ordering;
+
+    pub(super) use sys::Saved;
+
+    extern "C" {
+        fn raise(signum: c_int) -> c_int;
+    }
+
+    const SIGINT: c_int = 2;
+    const SIG_DFL: usize = 0;
+    const SIG_IGN: usize = 1;
+
+    extern "C" fn on_interrupt(_: c_int) {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+    }
+
+    /// Sets how SIGINT is handled, returning the handling to restore (if it was changed)
+    pub(super) fn set(mode: InterruptMode) -> Option<Saved> {
+        let handler = match mode {
+            InterruptMode::Exit => return None,
+            InterruptMode::ReturnErr => on_interrupt as extern "C" fn(c_int) as usize,
+            InterruptMode::Ignore => SIG_IGN,
+        };
+        // SAFETY: `handler` is SIG_IGN or a handler that only stores to an atomic
+        unsafe { sys::install(handler) }
+    }
+
+    extern "C" fn before_exit(_: c_int) {
+        let cleanup = CLEANUP.load(Ordering::SeqCst);
+        if cleanup != 0 {
+            // SAFETY: CLEANUP only ever holds a `fn()` given to `BeforeExit::start`
+            let cleanup: fn() = unsafe { std::mem::transmute::<usize, fn()>(cleanup) };
+            cleanup();
+        }
+        // SAFETY: this puts back the default handler, then ends the program with it, both
+        // of which are safe to do in a signal handler
+        unsafe {
+            sys::install(SIG_DFL);
+            raise(SIGINT);
+        }
+    }
+
+    /// Runs the `BeforeExit` cleanup on SIGINT if it would otherwise end the program,
+    /// returning the handling to restore (if it was changed)
+    pub(super) fn set_before_exit() -> Option<Saved> {
+        let handler = before_exit as extern "C" fn(c_int) as usize;
+        // SAFETY: `before_exit` only runs the async-signal-safe cleanup and re-raises
+        let saved = unsafe { sys::install(handler) }?;
+        match sys::handler_of(&saved) {
+            SIG_DFL => Some(saved),
+            _ => {
+                restore(saved);
+                None
+            }
+        }
+    }
+
+    /// Puts back the handling returned by `set` or `set_before_exit`
+    pub(super) fn restore(saved: Saved) {
+        // SAFETY: `saved` is how SIGINT was handled before `set`
+        unsafe { sys::restore(&saved) };
+    }
+
+    /// `sigaction`, where its struct's layout is the one below: that of macOS and of
+    /// Linux on these architectures
+    #[cfg(any(
+        target_os = "macos",
+        all(
+            target_os = "linux",
+            any(
+                target_arch = "x86",
+                target_arch = "x86_64",
+                target_arch = "arm",
+                target_arch = "aarch64",
+                target_arch = "riscv64"
+            )
+        )
+    ))]
+    mod sys {
+        use super::SIGINT;
+        use std::os::raw::c_int;
+
+        #[repr(C)]
+        #[derive(Clone, Copy)]
+        pub(crate) struct Saved {
+            handler: usize,
+            #[cfg(target_os = "linux")]
+            mask: [std::os::raw::c_ulong; 128 / std::mem::size_of::<std::os::raw::c_ulong>()],
+            #[cfg(target_os = "macos")]
+            mask: u32,
+            flags: c_int,
+            #[cfg(target_os = "linux")]
+            restorer: usize,
+        }
+
+        extern "C" {
+            fn sigaction(signum: c_int, act: *const Saved, old: *mut Saved) -> c_int;
+        }
+
+        /// Reads interrupted by the signal carry on, as they do for `signal`
+        #[cfg(target_os = "linux")]
+        const SA_RESTART: c_int = 0x1000_0000;
+        #[cfg(target_os = "macos")]
+        const SA_RESTART: c_int = 0x2;
+
+        /// Makes `handler` (or SIG_DFL or SIG_IGN) handle SIGINT, returning how it was
+        /// handled before, unless that failed
+        ///
+        /// # Safety
+        /// `handler` must only do what's safe in a signal handler
+        pub(crate) unsafe fn install(handler: usize) -> Option<Saved> {
+            // SAFETY: all zeroes is a valid `sigaction`, with an empty mask
+            let mut action: Saved = unsafe { std::mem::zeroed() };
+            action.handler = handler;
+            action.flags = SA_RESTART;
+            let mut saved = std::mem::MaybeUninit::<Saved>::uninit();
+            // SAFETY: both pointers are valid, and sigaction fills in `saved` on success
+            unsafe {
+                match sigaction(SIGINT, &action, saved.as_mut_ptr()) {
+                    0 => Some(saved.assume_init()),
+                    _ => None,
+                }
+            }
+        }
+
+        /// # Safety
+        /// `saved` must have come from `install`
+        pub(crate) unsafe fn restore(saved: &Saved) {
+            // SAFETY: `saved` is a complete `sigaction`, as the caller promises
+            unsafe { sigaction(SIGINT, saved, std::ptr::null_mut()) };
+        }
+
+        pub(crate) fn handler_of(saved: &Saved) -> usize {
+            saved.handler
+        }
+    }
+
+    /// `signal`, elsewhere, since the layout of `sigaction` there hasn't been checked
+    #[cfg(not(any(
+        target_os = "macos",
+        all(
+            target_os = "linux",
+            any(
+                target_arch = "x86",
+                target_arch = "x86_64",
+                target_arch = "arm",
+                target_arch = "aarch64",
+                target_arch = "riscv64"
+            )
+        )
+    )))]
+    mod sys {
+        use super::SIGINT;
+        use std::os::raw::c_int;
+
+        pub(crate) type Saved = usize;
+
+        extern "C" {
+            fn signal(signum: c_int, handler: usize) -> usize;
+        }
+
+        const SIG_ERR: usize = usize::MAX;
+
+        /// # Safety
+        /// `handler` must only do what's safe in a signal handler
+        pub(crate) unsafe fn install(handler: usize) -> Option<Saved> {
+            // SAFETY: the caller promises `handler` is fit to handle a signal
+            let saved = unsafe { signal(SIGINT, handler) };
+            (saved != SIG_ERR).then_some(saved)
+        }
+
+        /// # Safety
+        /// `saved` must have come from `install`
+        pub(crate) unsafe fn restore(saved: &Saved) {
+            // SAFETY: `saved` is what handled SIGINT before
+            unsafe { signal(SIGINT, *saved) };
+        }
+
+        pub(crate) fn handler_of(saved: &Saved) -> usize {
+            *saved
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Newline-delimited JSON transcripts of prompt interactions, enabled by the `json` feature.
+
+use std::fmt::Write;
+
+/// One finished interaction with the user
+pub(crate) struct Record<'a> {
+    pub(crate) prompt: &'a str,
+    pub(crate) raw_input: &'a str,
+    pub(crate) parsed: Option<&'a str>,
+    pub(crate) attempts: usize,
+    pub(crate) quit: bool,
+}
+
+impl Record<'_> {
+    /// Renders this record as a single line of JSON, without the trailing newline
+    pub(crate) fn to_json(&self) -> String {
+        let parsed = match self.parsed {
+            Some(p) => string(p),
+            None => "null".to_owned(),
+        };
+
+        format!(
+            r#"{{"prompt":{},"raw_input":{},"parsed":{},"attempts":{},"quit":{}}}"#,
+            string(self.prompt),
+            string(self.raw_input),
+            parsed,
+            self.attempts,
+            self.quit
+        )
+    }
+}
+
+/// Quotes and escapes `s` as a JSON string
+pub(crate) fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
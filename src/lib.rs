@@ -6,7 +6,8 @@
 //! - `cargo run --example hello` is a basic hello world program
 //!
 //! Example usage:
-//! ```
+//! ```no_run
+//! # use promptis::Input;
 //! let name: String = Input::new()
 //!     .prompt("Enter your name: ")
 //!     .wait();
@@ -15,7 +16,8 @@
 //! ```
 //!
 //! You can also set error messages for when the user messes up the input.
-//! ```
+//! ```no_run
+//! # use promptis::Input;
 //! let number: i32 = Input::new()
 //!     .err_msg("That wasn't a number; please try again")
 //!     .prompt("Enter a number: ")
@@ -25,19 +27,21 @@
 //! ```
 //!
 //! You can choose to just get the first input, regardless of whether it's good.
-//! ```
+//! ```no_run
+//! # use promptis::Input;
 //! let number: Option<i32> = Input::new()
 //!     .prompt("Enter a number: ")
 //!     .read();
 //!
 //! match number {
-//!     Some(n) => println!("Your number is: {}"),
+//!     Some(n) => println!("Your number is: {}", n),
 //!     None => println!("You didn't enter a number!")
 //! }
 //! ```
 //!
 //! You can specify a keyword that will end the program when entered
-//! ```
+//! ```no_run
+//! # use promptis::Input;
 //! let number: i32 = Input::new()
 //!     .quit("quit") // this can result in the program ending early
 //!     .prompt("Enter a number: ")
@@ -47,7 +51,8 @@
 //! ```
 //!
 //! You can re-use the same input object for multiple inputs.
-//! ```
+//! ```no_run
+//! # use promptis::Input;
 //! let mut input = Input::new()
 //!     .err_msg("Unexpected input; please retry")
 //!     .quit("quit");
@@ -59,14 +64,306 @@
 //! println!("Name: {}\nAge: {}\nWeight: {}", name, age, weight);
 //! ```
 
-use std::io::{stdin, stdout, Write};
+use std::any::Any;
+use std::borrow::Cow;
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::io::{stdout, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+mod color;
+mod config;
+mod echo;
+mod error;
+mod form;
+#[cfg(feature = "glob")]
+mod glob;
+#[cfg(feature = "hashing")]
+mod hash;
+mod interrupt;
+#[cfg(feature = "json")]
+mod json;
+mod live;
+mod menu;
+mod parse;
+mod poll;
+mod quit;
+#[cfg(feature = "semver")]
+mod semver;
+mod session;
+mod store;
+mod template;
+#[cfg(feature = "tty")]
+mod term;
+#[cfg(test)]
+mod tests;
+
+pub use color::Color;
+pub use config::InputConfig;
+pub use error::{InputError, Quit, SessionAborted, TooManyAttempts};
+pub use form::Form;
+pub use interrupt::InterruptMode;
+pub use menu::OptionStyle;
+pub use parse::{Step, ValueKind};
+pub use quit::QuitMatch;
+#[cfg(feature = "semver")]
+pub use semver::{Version, VersionError};
+pub use session::{Answers, PromptSession};
+
+/// Sets the prompt of an [Input] from `format!`-style arguments, through
+/// [Input::prompt_fmt], and evaluates to the `&mut Input` so a read can follow.
+///
+/// Example:
+/// ```
+/// use promptis::{prompt, Input};
+/// use std::io::Cursor;
+///
+/// let mut input = Input::new().chain_source(Box::new(Cursor::new("Ada\n")));
+/// let n = 1;
+/// let name: String = prompt!(input, "Player {}: ", n).wait();
+///
+/// assert_eq!(name, "Ada");
+/// ```
+#[macro_export]
+macro_rules! prompt {
+    ($input:expr, $($arg:tt)*) => {
+        $input.prompt_fmt(::std::format_args!($($arg)*))
+    };
+}
 
 /// Handler for easily getting user input from the command line
+///
+/// Reads normally block on stdin. Polling it instead, with [Input::try_read_now], a
+/// [Input::timeout], [InterruptMode::ReturnErr] or the `async` methods, starts a
+/// background thread that reads stdin for the rest of the program, and every read on
+/// any `Input` then takes its lines from that thread. So code that reads stdin itself
+/// afterwards may find lines already gone, and the terminal is no longer read key by
+/// key for [Input::max_len_live].
 #[derive(Debug, Default, Clone)]
 pub struct Input {
     user_prompt: String,
-    user_quit: Option<String>,
+    user_quit: Vec<String>,
+    quit_ignore_case: bool,
+    quit_match: QuitMatch,
     user_errmsg: Option<String>,
+    err_with: Option<Callback<MapFn>>,
+    on_error: Option<Callback<ErrorFn>>,
+    example: Option<String>,
+    soft_quit: bool,
+    quit_msg: Option<String>,
+    quit_flag: Cell<bool>,
+    hold_quit: Cell<bool>,
+    attempt: Cell<usize>,
+    index: Cell<Option<usize>>,
+    menu_retry: Option<String>,
+    page_size: usize,
+    option_style: OptionStyle,
+    auto_select_single: bool,
+    suggestions: bool,
+    max_len: Option<usize>,
+    live_count: bool,
+    min_len: Option<usize>,
+    allow_empty_selection: bool,
+    decorator: Option<Callback<MapFn>>,
+    auto_suffix: bool,
+    prompt_suffix: Option<String>,
+    input_map: Option<Callback<InputFn>>,
+    collapse_whitespace: bool,
+    lenient_numbers: bool,
+    keep_whitespace: bool,
+    warn_privileged: bool,
+    on_accept: Option<Callback<HookFn>>,
+    record_history: bool,
+    history: RefCell<Vec<String>>,
+    validator: Option<Check>,
+    bounds: Option<Callback<dyn Any>>,
+    bounds_check: Option<Check>,
+    canonical: Option<Check>,
+    canonical_warn: bool,
+    #[cfg(feature = "glob")]
+    glob_nomatch_literal: bool,
+    sticky: bool,
+    sticky_value: RefCell<Option<String>>,
+    default_value: Option<String>,
+    default_fn: Option<Callback<DefaultFn>>,
+    hide_default: bool,
+    fuzzy_distance: Option<usize>,
+    memory: Option<(String, PathBuf)>,
+    currency: Option<String>,
+    accept_leading: bool,
+    line_buf: String,
+    help: Option<(String, String)>,
+    answers: Option<Rc<RefCell<VecDeque<String>>>>,
+    answers_only: bool,
+    sources: Option<Sources>,
+    peeked: Rc<RefCell<Option<(String, bool)>>>,
+    list_skip_invalid: bool,
+    never_exit: bool,
+    no_exit_hint: bool,
+    show_quit_hint: bool,
+    sanitize: bool,
+    hidden: Cell<bool>,
+    println_after: bool,
+    prompt_once: bool,
+    no_reprompt: bool,
+    multiline: Option<String>,
+    prompted: Cell<bool>,
+    reason_prompt: Option<String>,
+    reason_on_yes: bool,
+    abort_repeats: usize,
+    max_attempts: usize,
+    color: bool,
+    force_color: bool,
+    prompt_color: Option<Color>,
+    error_color: Option<Color>,
+    success_color: Option<Color>,
+    success_msg: Option<String>,
+    echo_answers: bool,
+    #[cfg(feature = "tty")]
+    no_wrap: bool,
+    reader: Option<Source>,
+    writer: Option<Sink>,
+    to_stderr: bool,
+    #[cfg(feature = "json")]
+    transcript: Option<Sink>,
+    last_input: RefCell<(String, Option<String>)>,
+    eof: Cell<bool>,
+    timeout: Option<Duration>,
+    timed_out: Cell<bool>,
+    on_interrupt: InterruptMode,
+    interrupted: Cell<bool>,
+    read_error: ReadError,
+    fallible: Cell<bool>,
+    prompt_shown: Cell<bool>,
+}
+
+/// The error from the last failed read, kept for the `try_` methods. Clones start
+/// without one.
+#[derive(Debug, Default)]
+struct ReadError(RefCell<Option<std::io::Error>>);
+
+impl ReadError {
+    fn set(&self, e: std::io::Error) {
+        self.0.replace(Some(e));
+    }
+
+    fn take(&self) -> Option<std::io::Error> {
+        self.0.take()
+    }
+
+    fn is_set(&self) -> bool {
+        self.0.borrow().is_some()
+    }
+}
+
+impl Clone for ReadError {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// A shared, user-provided writer stored on an [Input]
+#[derive(Clone)]
+struct Sink(Rc<RefCell<dyn Write>>);
+
+impl fmt::Debug for Sink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sink(..)")
+    }
+}
+
+/// A shared, user-provided reader stored on an [Input]
+#[derive(Clone)]
+struct Source(Rc<RefCell<dyn BufRead>>);
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Source(..)")
+    }
+}
+
+/// Readers consulted in order for input, shared between clones of an [Input]
+#[derive(Clone)]
+struct Sources(Rc<RefCell<VecDeque<Box<dyn BufRead>>>>);
+
+impl fmt::Debug for Sources {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Sources(..)")
+    }
+}
+
+/// A closure transforming one string into another
+type MapFn = dyn Fn(&str) -> String;
+
+/// A closure rewriting a line of input
+type InputFn = dyn Fn(String) -> String;
+
+/// A closure producing a string on demand
+type DefaultFn = dyn Fn() -> String;
+
+/// A closure observing a string
+type HookFn = dyn Fn(&str);
+
+/// A closure observing a failed attempt: its number and the input
+type ErrorFn = dyn Fn(usize, &str);
+
+/// A check on the text of an answer, failing with the message to show (if any)
+type CheckFn = dyn Fn(&Input, &str) -> Result<(), Option<String>>;
+
+/// A predicate for one of the rules given to `wait_rules`
+type RuleFn<T> = dyn Fn(&T) -> bool;
+
+/// A shared, user-provided closure stored on an [Input]
+struct Callback<F: ?Sized>(Rc<F>);
+
+impl<F: ?Sized> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Callback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Callback(..)")
+    }
+}
+
+/// A check set by `validate`, `min`/`max` or `require_canonical` for values of one type.
+/// It parses the answer's text again itself, so reads of any `T`, borrowed ones
+/// included, can look for the checks that apply to them. Types are told apart by name,
+/// since a borrowed `T` has no `TypeId`.
+#[derive(Debug, Clone)]
+struct Check {
+    ty: &'static str,
+    f: Callback<CheckFn>,
+}
+
+impl Check {
+    fn new<T, F>(f: F) -> Self
+    where
+        T: std::str::FromStr + 'static,
+        F: Fn(&Input, &str, T) -> Result<(), Option<String>> + 'static,
+    {
+        Self {
+            ty: std::any::type_name::<T>(),
+            f: Callback(Rc::new(move |input: &Input, s: &str| match s.parse() {
+                Ok(value) => f(input, s, value),
+                Err(_) => Ok(()),
+            })),
+        }
+    }
+
+    /// Runs the check on `s` if it was set for values of type `T`
+    fn run<T>(&self, input: &Input, s: &str) -> Result<(), Option<String>> {
+        match self.ty == std::any::type_name::<T>() {
+            true => (self.f.0)(input, s),
+            false => Ok(()),
+        }
+    }
 }
 
 impl Input {
@@ -76,197 +373,6242 @@ impl Input {
     }
 
     /// Sets the prompt that will be displayed to the user.
+    ///
+    /// The prompt (like `err_msg`) may contain `{attempt}`, which is replaced with how
+    /// many times the user has been asked so far, starting at 1. Use `{{` and `}}` for
+    /// literal braces; any other brace is printed as written.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::RefCell;
+    /// use std::io::Cursor;
+    /// use std::rc::Rc;
+    ///
+    /// // Record each prompt as it's printed
+    /// let shown = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&shown);
+    /// let mut input = Input::new()
+    ///     .prompt_decorator(move |p| {
+    ///         log.borrow_mut().push(p.to_owned());
+    ///         p.to_owned()
+    ///     })
+    ///     .chain_source(Box::new(Cursor::new("x\n5\n")));
+    /// let n: u32 = input.prompt("{{try {attempt}}} Count: ").wait();
+    ///
+    /// assert_eq!(n, 5);
+    /// assert_eq!(*shown.borrow(), ["{try 1} Count: ", "{try 2} Count: "]);
+    /// ```
     pub fn prompt(&mut self, p: &str) -> &mut Self {
         self.user_prompt = p.to_owned();
         self
     }
 
+    /// Like [Input::prompt], but takes the prompt as [fmt::Arguments] and writes it into
+    /// the previous prompt's buffer, so a prompt built anew every time round a loop
+    /// needs no `String` of its own. The [prompt!] macro calls this with the
+    /// `format!`-style arguments it's given.
+    ///
+    /// The formatted text is used just like a prompt set with [Input::prompt], so
+    /// `{attempt}` in it is still filled in.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new().chain_source(Box::new(Cursor::new("3\n4\n")));
+    /// let mut sizes = Vec::new();
+    ///
+    /// for i in 1..=2 {
+    ///     sizes.push(input.prompt_fmt(format_args!("Item {}/{}: ", i, 2)).wait::<u32>());
+    /// }
+    ///
+    /// assert_eq!(sizes, [3, 4]);
+    /// ```
+    pub fn prompt_fmt(&mut self, args: fmt::Arguments<'_>) -> &mut Self {
+        use fmt::Write as _;
+
+        self.user_prompt.clear();
+        let _ = self.user_prompt.write_fmt(args);
+        self
+    }
+
     /// Sets a phrase that, when entered, will end the program early.
+    ///
+    /// This replaces any triggers set before; use [Input::quit_on] for several.
     pub fn quit(mut self, q: &str) -> Self {
-        self.user_quit = Some(q.into());
+        self.user_quit = vec![q.into()];
         self
     }
 
-    /// Sets an error message that will be displayed to the user if they enter something invalid.
-    pub fn err_msg(mut self, m: &str) -> Self {
-        self.user_errmsg = Some(m.into());
+    /// Sets several phrases that each work as the `quit` trigger, replacing any set
+    /// before.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .quit_on(&["quit", "exit", "q"])
+    ///     .quit_ignore_case(true)
+    ///     .soft_quit(true)
+    ///     .chain_source(Box::new(Cursor::new(" EXIT \nQ\nquitter\n")));
+    /// input.prompt("Name: ");
+    ///
+    /// assert_eq!(input.read::<String>(), None);
+    /// assert!(input.was_quit());
+    /// assert_eq!(input.read::<String>(), None);
+    /// assert!(input.was_quit());
+    /// assert_eq!(input.read::<String>().as_deref(), Some("quitter"));
+    /// ```
+    pub fn quit_on(mut self, triggers: &[&str]) -> Self {
+        self.user_quit = triggers.iter().map(|&q| q.to_owned()).collect();
         self
     }
 
-    /// Waits until the user responds with something that can be parsed to `T`.
+    /// Sets whether the `quit` triggers match regardless of case, so `QUIT` works like
+    /// `quit`. Defaults to `false`.
+    pub fn quit_ignore_case(mut self, i: bool) -> Self {
+        self.quit_ignore_case = i;
+        self
+    }
+
+    /// Sets how input is compared with the `quit` triggers: exactly (the default), by
+    /// prefix so extra words may follow the trigger, or anywhere in the input. This
+    /// applies to every trigger, along with [Input::quit_ignore_case].
     ///
-    /// If a `quit` trigger has been set and later read from the user, will exit early
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, QuitMatch};
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .quit_on(&["quit", ":q"])
+    ///     .quit_match(QuitMatch::Prefix)
+    ///     .soft_quit(true)
+    ///     .chain_source(Box::new(Cursor::new("quit now\n:q!\nsave :q\n")));
+    /// input.prompt("> ");
+    ///
+    /// assert_eq!(input.read::<String>(), None);
+    /// assert!(input.was_quit());
+    /// assert_eq!(input.read::<String>(), None);
+    /// assert!(input.was_quit());
+    /// assert_eq!(input.read::<String>().as_deref(), Some("save :q"));
+    /// ```
+    pub fn quit_match(mut self, m: QuitMatch) -> Self {
+        self.quit_match = m;
+        self
+    }
+
+    /// Sets whether a matched `quit` trigger returns control instead of ending the program.
+    ///
+    /// With this set, `read` returns `None` on the quit trigger and [Input::was_quit]
+    /// reports it. `wait` has to produce a value, so it simply prompts again.
     ///
     /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let mut input = Input::new().quit("quit").soft_quit(true);
+    ///
+    /// loop {
+    ///     let item: Option<String> = input.prompt("Item: ").read();
+    ///     if input.was_quit() {
+    ///         break;
+    ///     }
+    ///     println!("{:?}", item);
+    /// }
     /// ```
-    /// let data: i32 = Input::new()
-    ///     .prompt("Enter a number: ")
-    ///     .wait();
+    pub fn soft_quit(mut self, s: bool) -> Self {
+        self.soft_quit = s;
+        self
+    }
+
+    /// Sets a message printed when the `quit` trigger ends the program, e.g. "Goodbye!",
+    /// so the user can see quitting worked. By default the program ends silently.
     ///
-    /// println!("Your number is {}", data);
+    /// It's written to the same place as the prompts, on a line of its own, and stdout is
+    /// flushed before exiting. It isn't shown with `soft_quit`, where the program decides
+    /// what happens next.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let mut input = Input::new().quit("quit").on_quit_msg("Bye! Nothing was saved.");
+    ///
+    /// let name: String = input.prompt("Name: ").wait();
     /// ```
-    pub fn wait<T>(&self) -> T
-    where
-        T: std::str::FromStr,
-    {
-        let mut response = None;
+    /// What the user sees after typing `quit`:
+    /// ```plaintext
+    /// Name: quit
+    /// Bye! Nothing was saved.
+    /// ```
+    pub fn on_quit_msg(mut self, msg: &str) -> Self {
+        self.quit_msg = Some(msg.into());
+        self
+    }
 
-        while response.is_none() {
-            response = self.get_data();
-            self.check_error(&response);
-        }
+    /// Sets whether the `quit` trigger is ignored entirely, so this Input can never
+    /// end the program.
+    ///
+    /// This is a safety valve for embedding prompts in a larger application. With it
+    /// set, entering the quit trigger is as if nothing had been entered: the same
+    /// prompt is simply shown again, without `err_msg`, and [Input::was_quit] stays
+    /// `false`. It takes precedence over `soft_quit`. [Input::try_read_now] returns
+    /// `None` for it, as if no line had been entered yet.
+    ///
+    /// Nor does a broken pipe on the output end the program, as it otherwise would for
+    /// `wait`; it's treated like any other IO error, and input is still read.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// # use std::io::Cursor;
+    /// // Typing "quit" here just shows the prompt again
+    /// let mut input = Input::new().quit("quit").never_exit(true);
+    /// # let mut input = input.chain_source(Box::new(Cursor::new("quit\nAlice\n")));
+    /// let name: String = input.prompt("Name: ").wait();
+    ///
+    /// assert_eq!(name, "Alice");
+    /// assert!(!input.was_quit());
+    /// ```
+    pub fn never_exit(mut self, n: bool) -> Self {
+        self.never_exit = n;
+        self
+    }
 
-        // At this point we know that this holds a value
-        // so unwrapping should be fine.
-        response.unwrap()
+    /// Sets whether a hint on how to quit is shown when the user enters something like
+    /// `quit`, `exit` or `q` that doesn't parse, while no `quit` trigger is set (on by default).
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let n: i32 = Input::new().prompt("Number: ").wait();
+    /// ```
+    /// What the user would see after entering `exit`:
+    /// ```plaintext
+    /// Number: exit
+    /// Hint: to quit, press Ctrl-C
+    /// Number:
+    /// ```
+    pub fn exit_hint(mut self, h: bool) -> Self {
+        self.no_exit_hint = !h;
+        self
     }
 
-    /// Checks if the user's input is the quit trigger, and if so, ends the program
-    fn check_quit(&self, message: &str) {
-        if let Some(trigger) = &self.user_quit {
-            if trigger == message.trim() {
-                std::process::exit(0);
-            }
-        }
+    /// Sets whether every prompt tells the user how to quit, while a `quit` trigger is
+    /// set (off by default): `"Enter: "` is shown as `"Enter (type 'quit' to exit): "`,
+    /// with each trigger listed when there are several.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let shown = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&shown);
+    /// let mut input = Input::new()
+    ///     .quit_on(&["quit", "exit"])
+    ///     .show_quit_hint(true)
+    ///     .prompt_decorator(move |p| {
+    ///         log.borrow_mut().push(p.to_owned());
+    ///         p.to_owned()
+    ///     })
+    ///     .with_reader(&b"ten\n10\n"[..]);
+    /// let n: u32 = input.prompt("Count: ").wait();
+    ///
+    /// assert_eq!(n, 10);
+    /// assert_eq!(
+    ///     *shown.borrow(),
+    ///     ["Count (type 'quit' or 'exit' to exit): "; 2]
+    /// );
+    /// ```
+    pub fn show_quit_hint(mut self, s: bool) -> Self {
+        self.show_quit_hint = s;
+        self
     }
 
-    /// Checks whether `response` was entered incorrectly, and if so, prints the error message
-    fn check_error<T>(&self, response: &Option<T>) {
-        if response.is_none() {
-            if let Some(msg) = &self.user_errmsg {
-                println!("{}", msg);
-            }
-        }
+    /// Returns whether the most recent read matched the `quit` trigger.
+    ///
+    /// This is reset at the start of every read, so it only ever describes the last one.
+    /// It's only useful alongside [Input::soft_quit], since otherwise the program ends.
+    pub fn was_quit(&self) -> bool {
+        self.quit_flag.get()
+    }
+
+    /// Sets an error message that will be displayed to the user if they enter something invalid.
+    pub fn err_msg(mut self, m: &str) -> Self {
+        self.user_errmsg = Some(m.into());
+        self
     }
 
-    /// Handles getting data from the user
-    fn get_data<T>(&self) -> Option<T>
+    /// Sets a function that builds the error message for input that couldn't be used,
+    /// from the input itself (trimmed), so the message can say what was wrong with it.
+    /// It takes precedence over `err_msg`, while messages from checks like
+    /// [Input::wait_check] are still shown as they are.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .err_msg("Invalid input")
+    ///     .err_with(|s| format!("'{}' is not a valid integer", s))
+    ///     .with_reader(&b" foo \n12\n"[..]);
+    /// let n: i32 = input.prompt("Count: ").wait();
+    ///
+    /// assert_eq!(n, 12);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Count:  foo
+    /// 'foo' is not a valid integer
+    /// Count: 12
+    /// ```
+    pub fn err_with<F>(mut self, f: F) -> Self
     where
-        T: std::str::FromStr,
+        F: Fn(&str) -> String + 'static,
     {
-        print!("{}", self.user_prompt);
-        self.handle_io(|| stdout().flush());
-        let mut buffer = String::new();
-        self.handle_io(|| stdin().read_line(&mut buffer));
-        self.check_quit(&buffer);
-        buffer.trim().parse().ok()
+        self.err_with = Some(Callback(Rc::new(f)));
+        self
     }
 
-    /// Handles [std::io] operations; will simply print that an error
-    /// occurred and continue on
-    fn handle_io<T, F>(&self, mut io: F)
+    /// Sets a hook that's called each time input is rejected, with the number of the
+    /// attempt (counting from 1 in every `wait`, `try_wait`, ...) and the input (trimmed),
+    /// e.g. to give more help the more tries it takes. It's called in addition to the
+    /// usual error message, after it, and not for the quit trigger. Choices that
+    /// `wait_opts` and its variants reject count as attempts too.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::RefCell;
+    /// use std::io::Cursor;
+    /// use std::rc::Rc;
+    ///
+    /// let seen = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&seen);
+    /// let mut input = Input::new()
+    ///     .err_msg("Not a valid date")
+    ///     .on_error(move |attempt, line| {
+    ///         if attempt >= 2 {
+    ///             println!("Still not valid; the format is dd/mm/yyyy");
+    ///         }
+    ///         log.borrow_mut().push((attempt, line.to_owned()));
+    ///     })
+    ///     .chain_source(Box::new(Cursor::new("soon\n12-03\n12\nx\n3\n")));
+    ///
+    /// let day: u8 = input.prompt("Day: ").wait();
+    /// let month: u8 = input.prompt("Month: ").wait();
+    ///
+    /// assert_eq!((day, month), (12, 3));
+    /// assert_eq!(
+    ///     *seen.borrow(),
+    ///     [(1, "soon".to_owned()), (2, "12-03".to_owned()), (1, "x".to_owned())]
+    /// );
+    /// ```
+    pub fn on_error<F>(mut self, f: F) -> Self
     where
-        F: FnMut() -> std::io::Result<T>,
+        F: Fn(usize, &str) + 'static,
     {
-        if let Err(e) = io() {
-            println!("IO Error: {}; Continuing...", e);
-        }
+        self.on_error = Some(Callback(Rc::new(f)));
+        self
     }
 
-    /// Presents a series of options to the user from which they can choose one.
-    ///
-    /// This function will guarantee that the user chooses something present in `opts`
+    /// Sets an example of valid input, shown after input that couldn't be parsed.
     ///
-    /// This function can return `Err` if the user's option doesn't parse into `T`
+    /// Without an `err_msg`, the user sees `Invalid. Example: <example>`; with one, the
+    /// `err_msg` comes first and `Example: <example>` follows on its own line. Messages
+    /// from checks like [Input::wait_check] are shown without the example.
     ///
     /// Example:
     /// ```
-    /// let choice: String = Input::new()
-    ///     .wait_opts(&["First", "Second", "Third"], "Enter your choice: ")
-    ///     .unwrap();
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .example("8080")
+    ///     .with_reader(&b"http\n8080\n"[..]);
+    /// let port: u16 = input.prompt("Port: ").wait();
     ///
-    /// match choice.as_str() {
-    ///     "First" => println!("1st!"),
-    ///     "Second" => println!("2nd"),
-    ///     "Third" => println!("3rd..")
-    /// }
+    /// assert_eq!(port, 8080);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Port: http
+    /// Invalid. Example: 8080
+    /// Port: 8080
     /// ```
+    pub fn example(mut self, e: &str) -> Self {
+        self.example = Some(e.to_owned());
+        self
+    }
+
+    /// Sets a predicate that values must pass, after parsing, for `wait` and `read` to
+    /// accept them.
     ///
-    /// The user in the above case would see the following:
-    /// ```markdown
-    /// 1. First
-    /// 2. Second
-    /// 3. Third
-    /// Enter your choice:
+    /// A value that parses but fails `f` is treated like input that didn't parse: `wait`
+    /// shows `err_msg` and asks again, and `read` returns `None`. `f` only applies when
+    /// values of the type it takes are read; setting another replaces it.
+    ///
+    /// The same goes for every method that parses one `T` of the caller's choosing from
+    /// each answer: `try_wait`, `wait_or_quit`, `wait_async`, `try_read`, `read_async`,
+    /// `try_read_now`, `read_optional`, `read_raw`, `read_ctx`, `collect_n`, `wait_collect`, `wait_list`,
+    /// `wait_until_value`, `wait_unique`, `wait_nonzero`, `wait_leq`, `wait_geq`,
+    /// `wait_check`, `wait_rules`, `wait_checked_with_spinner`, `wait_confirmed`,
+    /// `wait_confirm_format`, `wait_edit` and `wait_validated_async`. Methods that parse
+    /// several values from an answer, like `wait_vec`, or a type of their own, like
+    /// `wait_index`, don't apply it.
+    ///
+    /// Example:
     /// ```
-    pub fn wait_opts<T>(&self, opts: &[T], p: &str) -> T
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .err_msg("Please enter a number from 1 to 100")
+    ///     .validate(|n: &u32| (1..=100).contains(n))
+    ///     .chain_source(Box::new(Cursor::new("250\n0\n42\n150\n")));
+    ///
+    /// let n: u32 = input.prompt("Number: ").wait();
+    /// assert_eq!(n, 42);
+    ///
+    /// let m: Option<u32> = input.read();
+    /// assert_eq!(m, None);
+    /// ```
+    pub fn validate<T, F>(mut self, f: F) -> Self
     where
-        T: std::fmt::Display + Clone,
+        T: std::str::FromStr + 'static,
+        F: Fn(&T) -> bool + 'static,
     {
-        let index;
-
-        // This is so that the input object will respect err_msg rules and quit triggers
-        let mut ic = self.clone();
-
-        loop {
-            for (i, v) in opts.iter().enumerate() {
-                println!("{}. {}", i + 1, v);
-            }
-
-            let result = ic.prompt(p).wait();
-
-            if (1..=opts.len()).contains(&result) {
-                index = result - 1;
-                break;
-            } else {
-                println!(
-                    "Please enter a number within the bounds {:?}",
-                    1..=opts.len()
-                );
-            }
-        }
-
-        opts[index].clone()
+        self.validator = Some(Check::new(move |_, _, value: T| match f(&value) {
+            true => Ok(()),
+            false => Err(None),
+        }));
+        self
     }
 
-    /// Presents a simple "yes/no" option to the user, returning their choice
+    /// Sets whether values of type `T` must be entered exactly as they're displayed, e.g.
+    /// `7` rather than `007` or `+7` for an `i32`, when read by the same methods as
+    /// [Input::validate]. Surrounding whitespace doesn't count.
     ///
-    /// This is useful for binary decisions, i.e. asking for confirmation before progressing
+    /// Input written some other way is rejected with a message suggesting the usual
+    /// form, or with [Input::canonical_warn_only], accepted after a note saying how it
+    /// was read. Setting this for another type replaces it.
     ///
     /// Example:
     /// ```
-    /// if Input::new().choose("Continue?") {
-    ///     println!("You continued.");
-    /// }
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .require_canonical::<i32>(true)
+    ///     .chain_source(Box::new(Cursor::new("007\n7\n")));
+    /// let n: i32 = input.prompt("Agent: ").wait();
+    ///
+    /// assert_eq!(n, 7);
     /// ```
-    /// What the user would see:
+    /// What the user sees:
     /// ```plaintext
-    /// Continue? [y/n]
+    /// Agent: 007
+    /// '007' isn't written the usual way; did you mean 7?
+    /// Agent: 7
     /// ```
-    pub fn choose(&self, p: &str) -> bool {
-        loop {
-            let inp: char = self.clone().prompt(&format!("{} [y/n] ", p)).wait();
-            match inp.to_ascii_uppercase() {
-                'Y' => {
-                    return true;
-                }
-                'N' => {
-                    return false;
+    pub fn require_canonical<T>(mut self, r: bool) -> Self
+    where
+        T: std::str::FromStr + fmt::Display + 'static,
+    {
+        self.canonical = r.then(|| {
+            Check::new(|input, s, value: T| {
+                let shown = value.to_string();
+                if shown != s.trim() && input.canonical_warn {
+                    input.write_line(&format!("'{}' was read as {}", s.trim(), shown));
+                } else if shown != s.trim() {
+                    return Err(Some(format!(
+                        "'{}' isn't written the usual way; did you mean {}?",
+                        s.trim(),
+                        shown
+                    )));
                 }
-                _ => {}
-            }
-        }
+                Ok(())
+            })
+        });
+        self
     }
 
-    /// Similar to `wait`, except will return after the user inputs anything.
-    ///
-    /// If the user input doesn't parse to `T`, `None` is returned.
+    /// Sets whether [Input::require_canonical] accepts input written some other way after
+    /// noting how it was read (`'007' was read as 7`), instead of asking again. Defaults
+    /// to `false`.
     ///
-    /// Example
+    /// Example:
     /// ```
-    /// let data: Option<i32> = Input::new()
-    ///     .prompt("Enter a number: ")
-    ///     .read();
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
     ///
-    /// match data {
-    ///     Some(number) => println!("You entered {}", number),
-    ///     None => println!("You didn't enter a number")
-    /// }
+    /// let mut input = Input::new()
+    ///     .require_canonical::<i32>(true)
+    ///     .canonical_warn_only(true)
+    ///     .chain_source(Box::new(Cursor::new("007\n")));
+    /// let n: i32 = input.prompt("Agent: ").wait();
+    ///
+    /// assert_eq!(n, 7);
     /// ```
-    pub fn read<T>(&self) -> Option<T>
-    where
-        T: std::str::FromStr,
+    pub fn canonical_warn_only(mut self, w: bool) -> Self {
+        self.canonical_warn = w;
+        self
+    }
+
+    /// Sets a prompt that `wait_opts` shows instead of its original prompt when
+    /// re-asking after an invalid choice, e.g. just `"Choice: "`.
+    ///
+    /// When unset, the original prompt is repeated.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let color = Input::new()
+    ///     .menu_retry_prompt("Choice: ")
+    ///     .with_reader(&b"3\n2\n"[..])
+    ///     .wait_opts(&["Red", "Green"], "Which color do you like best? ");
+    ///
+    /// assert_eq!(color, "Green");
+    /// ```
+    /// What the user would see after entering `3`:
+    /// ```plaintext
+    /// 1. Red
+    /// 2. Green
+    /// Which color do you like best? 3
+    /// Please enter a number within the bounds 1..=2
+    /// 1. Red
+    /// 2. Green
+    /// Choice:
+    /// ```
+    pub fn menu_retry_prompt(mut self, p: &str) -> Self {
+        self.menu_retry = Some(p.into());
+        self
+    }
+
+    /// Sets a function that transforms every prompt right before it's printed,
+    /// e.g. to add a consistent leading "» ".
+    ///
+    /// This applies to every prompt shown, including those of `wait_opts` and `choose`.
+    /// It receives the complete prompt text, and whatever it returns is printed as-is.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let name: String = Input::new()
+    ///     .prompt_decorator(|p| format!("» {}", p))
+    ///     .with_reader(&b"Ada\n"[..])
+    ///     .prompt("Enter your name: ")
+    ///     .wait();
+    ///
+    /// assert_eq!(name, "Ada");
+    /// ```
+    /// What the user would see:
+    /// ```plaintext
+    /// » Enter your name:
+    /// ```
+    pub fn prompt_decorator<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> String + 'static,
+    {
+        self.decorator = Some(Callback(Rc::new(f)));
+        self
+    }
+
+    /// Sets whether every prompt is shown ending in exactly one space (off by default),
+    /// however much whitespace it was written with: `"Name:"` and `"Name:   "` both
+    /// become `"Name: "`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .auto_suffix(true)
+    ///     .with_reader(&b"Ada\nLovelace\n"[..]);
+    ///
+    /// let _: String = input.prompt("First name:").wait();
+    /// let _: String = input.prompt("Last name:   ").wait();
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// First name: Ada
+    /// Last name: Lovelace
+    /// ```
+    pub fn auto_suffix(mut self, a: bool) -> Self {
+        self.auto_suffix = a;
+        self
+    }
+
+    /// Sets a marker, like `"> "` or `": "`, that every prompt is shown ending with. Any
+    /// whitespace the prompt ends with, and the marker itself if it's already there, is
+    /// replaced, so the prompts on a reused Input all end the same way.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .prompt_suffix(": ")
+    ///     .with_reader(&b"Ada\n36\n"[..]);
+    ///
+    /// let _: String = input.prompt("Name").wait();
+    /// let _: u32 = input.prompt("Age: ").wait();
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Name: Ada
+    /// Age: 36
+    /// ```
+    pub fn prompt_suffix(mut self, s: &str) -> Self {
+        self.prompt_suffix = Some(s.to_owned());
+        self
+    }
+
+    /// Sets a function that rewrites the (trimmed) input before it's parsed, e.g. to strip
+    /// separators or normalize case in one place.
+    ///
+    /// The quit trigger and `help` keyword are checked against the input as entered, so
+    /// a mapping can't hide them. An answer filled in by a default goes through it too.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .map_input(|s| s.replace(['-', ' ', '(', ')'], ""))
+    ///     .chain_source(Box::new(Cursor::new("(555) 123-4567\n")));
+    /// let phone: u64 = input.prompt("Phone: ").wait();
+    ///
+    /// assert_eq!(phone, 5551234567);
+    ///
+    /// // "quit" still quits, even though the mapping would turn it into "QUIT"
+    /// let mut input = Input::new()
+    ///     .quit("quit")
+    ///     .soft_quit(true)
+    ///     .map_input(|s| s.to_uppercase())
+    ///     .chain_source(Box::new(Cursor::new("quit\n")));
+    /// assert_eq!(input.prompt("Code: ").read::<String>(), None);
+    /// assert!(input.was_quit());
+    /// ```
+    pub fn map_input<F>(mut self, f: F) -> Self
+    where
+        F: Fn(String) -> String + 'static,
+    {
+        self.input_map = Some(Callback(Rc::new(f)));
+        self
+    }
+
+    /// Sets whether runs of spaces, tabs and other whitespace inside the input are squeezed
+    /// down to a single space before it's parsed (off by default), e.g. for names pasted
+    /// with odd spacing. The input is trimmed either way.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .collapse_whitespace(true)
+    ///     .chain_source(Box::new(Cursor::new("  John   Q \t Public \n")));
+    /// let name: String = input.prompt("Full name: ").wait();
+    ///
+    /// assert_eq!(name, "John Q Public");
+    /// ```
+    pub fn collapse_whitespace(mut self, c: bool) -> Self {
+        self.collapse_whitespace = c;
+        self
+    }
+
+    /// Sets whether numbers typed with digit grouping, like `1,000`, `1 000` or `1_000`, are
+    /// accepted (off by default). When the input doesn't parse as typed but is a number
+    /// grouped in threes by commas, spaces or underscores, it's parsed again without them;
+    /// anything else, such as `1,00` or `1,,000`, is still rejected.
+    ///
+    /// Since this only comes into play when parsing fails, a `String` answer of `1,000`
+    /// is kept as typed.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .lenient_numbers(true)
+    ///     .chain_source(Box::new(Cursor::new("1,00\n1,250,000\n-2 500.75\n")));
+    /// let units: usize = input.prompt("Units: ").wait();
+    /// let balance: f64 = input.prompt("Balance: ").wait();
+    ///
+    /// assert_eq!(units, 1_250_000);
+    /// assert_eq!(balance, -2500.75);
+    /// ```
+    pub fn lenient_numbers(mut self, l: bool) -> Self {
+        self.lenient_numbers = l;
+        self
+    }
+
+    /// Sets the most characters an answer may have, for any type read. Longer input is
+    /// rejected before it's parsed, with a message giving its length against the limit,
+    /// e.g. `Too long (23/20)`, and asked for again by `wait` (or turned down by `read`).
+    ///
+    /// The length is that of the trimmed input in `char`s (Unicode scalar values), not
+    /// bytes or grapheme clusters, so `é` written as `e` plus a combining accent counts
+    /// as two.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "al\nthe_longest_username_yet\n  ålesund  \n";
+    /// let mut input = Input::new()
+    ///     .min_len(3)
+    ///     .max_len(20)
+    ///     .chain_source(Box::new(Cursor::new(lines)));
+    /// let user: String = input.prompt("Username: ").wait();
+    ///
+    /// assert_eq!(user, "ålesund");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Username: al
+    /// Too short (2/3); please enter at least 3 characters
+    /// Username: the_longest_username_yet
+    /// Too long (24/20); please enter at most 20 characters
+    /// Username:   ålesund
+    /// ```
+    pub fn max_len(mut self, max: usize) -> Self {
+        self.max_len = Some(max);
+        self
+    }
+
+    /// Sets the fewest characters an answer may have, counted like [Input::max_len].
+    /// Shorter input, including an empty line, is rejected before it's parsed with a
+    /// message like `Too short (2/3)`.
+    pub fn min_len(mut self, min: usize) -> Self {
+        self.min_len = Some(min);
+        self
+    }
+
+    /// Like [Input::max_len], but when the user types at a terminal, a running count of
+    /// the characters typed is shown after the cursor, e.g. `(12/20)`, updated with
+    /// each key, and keys that would go past the limit are refused with a bell.
+    /// Backspace works as usual, and with [Input::record_history] the up and down arrow
+    /// keys recall earlier answers, while other control keys are ignored.
+    ///
+    /// The terminal is read key by key (on Linux and macOS) only when both stdin and the
+    /// output are terminals, and not for `secret` input, with a `timeout` or
+    /// [InterruptMode::ReturnErr], or once [Input::try_read_now] has started reading
+    /// stdin in the background. Otherwise, as for piped input, the limit is checked once
+    /// the line has been entered, just as `max_len` does.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .max_len_live(8)
+    ///     .chain_source(Box::new(Cursor::new("much too long\nshort\n")));
+    /// let nick: String = input.prompt("Nickname: ").wait();
+    ///
+    /// assert_eq!(nick, "short");
+    /// ```
+    /// At a terminal, the user instead sees the count while typing, and can't go past it:
+    /// ```plaintext
+    /// Nickname: much too (8/8)
+    /// ```
+    pub fn max_len_live(mut self, max: usize) -> Self {
+        self.max_len = Some(max);
+        self.live_count = true;
+        self
+    }
+
+    /// Sets whether spaces around the input are kept (off by default), so that `wait`,
+    /// `read` and their `try_` forms only strip the line ending (`\n` or `\r\n`) before
+    /// parsing. Useful for values where leading or trailing spaces matter, like passwords.
+    ///
+    /// The quit trigger still matches with the spaces trimmed.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .keep_whitespace(true)
+    ///     .chain_source(Box::new(Cursor::new("  indented \r\n")));
+    /// let code: String = input.prompt("Code: ").wait();
+    ///
+    /// assert_eq!(code, "  indented ");
+    /// ```
+    pub fn keep_whitespace(mut self, k: bool) -> Self {
+        self.keep_whitespace = k;
+        self
+    }
+
+    /// Sets a hook that's called with the accepted input every time a read on this `Input`
+    /// produces a value, from any method, for cross-cutting work like logging or autosave.
+    ///
+    /// The hook gets the accepted text trimmed (or the default it stood in for). It isn't
+    /// called for the quit trigger or for input that was rejected and re-prompted.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::RefCell;
+    /// use std::io::Cursor;
+    /// use std::rc::Rc;
+    ///
+    /// let seen = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&seen);
+    /// let mut input = Input::new()
+    ///     .on_accept(move |line| log.borrow_mut().push(line.to_owned()))
+    ///     .chain_source(Box::new(Cursor::new("Ada\nnope\n36\n")));
+    ///
+    /// let name: String = input.prompt("Name: ").wait();
+    /// let age: u32 = input.prompt("Age: ").wait();
+    ///
+    /// assert_eq!((name.as_str(), age), ("Ada", 36));
+    /// assert_eq!(*seen.borrow(), ["Ada", "36"]);
+    /// ```
+    pub fn on_accept<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.on_accept = Some(Callback(Rc::new(f)));
+        self
+    }
+
+    /// Sets whether the input accepted by each read on this `Input` is recorded, oldest
+    /// first, for [Input::history] to hand back, e.g. to save between sessions and give
+    /// back to [Input::with_history] next time. Answers to a [Input::secret] prompt are
+    /// never recorded.
+    ///
+    /// When the user types at a terminal, the up and down arrow keys step through the
+    /// history recorded so far, like in a shell, replacing what was typed. This needs the
+    /// terminal to be read key by key, which happens only when [Input::max_len_live]
+    /// would count keys; otherwise the terminal's own line editing is all there is.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .record_history(true)
+    ///     .chain_source(Box::new(Cursor::new("ls\nnope\n5\n")));
+    ///
+    /// let _: String = input.prompt("$ ").wait();
+    /// let _: u32 = input.prompt("Count: ").wait();
+    ///
+    /// // Only what was accepted is kept, so not "nope"
+    /// assert_eq!(*input.history(), ["ls", "5"]);
+    /// ```
+    pub fn record_history(mut self, r: bool) -> Self {
+        self.record_history = r;
+        self
+    }
+
+    /// Starts the history recorded with [Input::record_history] from `entries`, such as
+    /// those saved from [Input::history] in an earlier session.
+    pub fn with_history<I>(self, entries: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        self.history.replace(entries.into_iter().collect());
+        self
+    }
+
+    /// Borrows the input recorded so far with [Input::record_history], oldest first.
+    /// A read that records an answer panics while it's still borrowed, so drop it first.
+    ///
+    /// The arrow keys only recall these entries while the terminal is read key by key,
+    /// as for [Input::max_len_live]; a line read all at once can't be edited with them.
+    pub fn history(&self) -> Ref<'_, [String]> {
+        Ref::map(self.history.borrow(), Vec::as_slice)
+    }
+
+    /// Sets whether a glob pattern given to `wait_paths` that matches no files is kept
+    /// as a literal path, rather than re-prompting the user.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::path::PathBuf;
+    ///
+    /// let paths = Input::new()
+    ///     .allow_empty_glob(true)
+    ///     .with_reader(&b"no-such-dir/*.md\n"[..])
+    ///     .wait_paths("Files: ");
+    ///
+    /// assert_eq!(paths, [PathBuf::from("no-such-dir/*.md")]);
+    /// ```
+    #[cfg(feature = "glob")]
+    pub fn allow_empty_glob(mut self, a: bool) -> Self {
+        self.glob_nomatch_literal = a;
+        self
+    }
+
+    /// Sets whether each accepted answer becomes the default for the next prompt
+    /// on this Input.
+    ///
+    /// The remembered answer is shown in brackets in the prompt, and entering nothing
+    /// accepts it again. Turning this off forgets the remembered answer.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .sticky_default(true)
+    ///     .with_reader(&b"kg\n\n"[..]);
+    ///
+    /// let first: String = input.prompt("Unit: ").wait();
+    /// // The user just presses Enter here, so this is the same as `first`
+    /// let second: String = input.prompt("Unit: ").wait();
+    ///
+    /// assert_eq!((first.as_str(), second.as_str()), ("kg", "kg"));
+    /// ```
+    /// What the user would see on the second prompt:
+    /// ```plaintext
+    /// Unit [kg]:
+    /// ```
+    pub fn sticky_default(mut self, s: bool) -> Self {
+        self.sticky = s;
+        if !s {
+            self.sticky_value.take();
+        }
+        self
+    }
+
+    /// Remembers answers across runs of the program: the value last accepted for `key`
+    /// is loaded from the file at `path` as the default (shown in the prompt and used
+    /// when the user just presses Enter), and each value accepted is saved back there.
+    ///
+    /// On the first run, or if the file can't be read, there's simply no default. The
+    /// file holds one `key=value` line per key, so keys shouldn't contain `=`, and other
+    /// keys' lines are left alone; it can be shared by several Inputs with
+    /// different keys.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let name = format!("promptis-remember-{}.txt", std::process::id());
+    /// let path = std::env::temp_dir().join(name);
+    ///
+    /// // First run: nothing saved yet, so a name has to be typed
+    /// let mut input = Input::new()
+    ///     .remember("name", &path)
+    ///     .chain_source(Box::new(Cursor::new("Ada\n")));
+    /// let name: String = input.prompt("Name: ").wait();
+    /// assert_eq!(name, "Ada");
+    ///
+    /// // Next run: pressing Enter gives back the saved name
+    /// let mut input = Input::new()
+    ///     .remember("name", &path)
+    ///     .chain_source(Box::new(Cursor::new("\n")));
+    /// let name: String = input.prompt("Name: ").wait();
+    /// assert_eq!(name, "Ada");
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    /// What the user would see on the second run:
+    /// ```plaintext
+    /// Name [Ada]:
+    /// ```
+    pub fn remember<P: AsRef<Path>>(mut self, key: &str, path: P) -> Self {
+        let path = path.as_ref().to_owned();
+        self.sticky_value.replace(store::load(&path, key));
+        self.memory = Some((key.to_owned(), path));
+        self
+    }
+
+    /// Sets the answer used when the user just presses Enter, instead of asking again.
+    /// It's shown in brackets in the prompt, as in `Port [8080]: `, unless turned off
+    /// with [Input::show_default].
+    ///
+    /// The default goes through the same parsing as typed input, so `wait` and `read`
+    /// give back `d` itself (`read` returns `Some(d)` rather than `None`), and anything
+    /// typed still takes its place. A `sticky_default` or `remember`ed answer, once
+    /// there is one, is used instead.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .default_value(8080)
+    ///     .chain_source(Box::new(Cursor::new("\n9000\n\n")));
+    /// input.prompt("Port: ");
+    ///
+    /// assert_eq!(input.wait::<u16>(), 8080);
+    /// assert_eq!(input.wait::<u16>(), 9000);
+    /// assert_eq!(input.read::<u16>(), Some(8080));
+    /// ```
+    /// What the user sees for the first prompt:
+    /// ```plaintext
+    /// Port [8080]:
+    /// ```
+    pub fn default_value<T: fmt::Display>(mut self, d: T) -> Self {
+        self.default_value = Some(d.to_string());
+        self
+    }
+
+    /// Sets a function that works out the answer used when the user just presses Enter,
+    /// for defaults that depend on the state of the system, like the current directory.
+    ///
+    /// `f` is only called when empty input is actually read (and again each time), never
+    /// before, so it costs nothing for prompts answered some other way. That also means
+    /// the default isn't shown in the prompt; mention it there if it should be. Its
+    /// result is parsed like typed input. A [Input::default_value] is used instead if
+    /// there is one.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::Cell;
+    /// use std::io::Cursor;
+    /// use std::rc::Rc;
+    ///
+    /// let calls = Rc::new(Cell::new(0));
+    /// let counter = Rc::clone(&calls);
+    /// let mut input = Input::new()
+    ///     .default_fn(move || {
+    ///         counter.set(counter.get() + 1);
+    ///         std::env::current_dir().unwrap().display().to_string()
+    ///     })
+    ///     .chain_source(Box::new(Cursor::new("/tmp\n\n")));
+    /// input.prompt("Directory (default: current): ");
+    ///
+    /// assert_eq!(input.wait::<String>(), "/tmp");
+    /// assert_eq!(calls.get(), 0);
+    ///
+    /// let dir: String = input.wait();
+    /// assert_eq!(dir, std::env::current_dir().unwrap().display().to_string());
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    pub fn default_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn() -> String + 'static,
+    {
+        self.default_fn = Some(Callback(Rc::new(f)));
+        self
+    }
+
+    /// Sets how long to wait for the user to answer each time they're asked, after which
+    /// the read carries on as if they'd just pressed Enter: a [Input::default_value] (or
+    /// other default) is used if there is one. Otherwise `wait` asks again, with a fresh
+    /// timeout, while `read` returns `None` and the `try_` methods give
+    /// [InputError::TimedOut].
+    ///
+    /// This only limits reading from stdin, which then happens on a background thread.
+    /// An answer typed too late isn't lost: it's used for the next read. Lines from a
+    /// `chain_source`, `answers_file` or `with_reader` are read as usual.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// use std::time::Duration;
+    ///
+    /// let mut input = Input::new()
+    ///     .timeout(Duration::from_secs(30))
+    ///     .default_value("en");
+    /// // Nobody at the kiosk? Carry on in English after 30 seconds
+    /// let language: String = input.prompt("Language: ").wait();
+    /// ```
+    pub fn timeout(mut self, t: Duration) -> Self {
+        self.timeout = Some(t);
+        self
+    }
+
+    /// Sets what pressing Ctrl-C does while waiting for the user to type an answer on
+    /// stdin. By default ([InterruptMode::Exit]) nothing is changed, so it ends the
+    /// program as usual.
+    ///
+    /// With [InterruptMode::ReturnErr], the read is abandoned: the `try_` methods return
+    /// [InputError::Interrupted], `read` returns `None`, and `wait` asks again. This lets
+    /// Ctrl-C mean "go back a step". With [InterruptMode::Ignore], Ctrl-C does nothing.
+    ///
+    /// Ctrl-C is only caught during the read itself (on Unix-like systems); at any other
+    /// time it does whatever it did before.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::{Input, InputError, InterruptMode};
+    /// let mut input = Input::new().on_interrupt(InterruptMode::ReturnErr);
+    ///
+    /// match input.prompt("Branch name: ").try_wait::<String>() {
+    ///     Ok(name) => println!("Creating {}", name),
+    ///     Err(InputError::Interrupted) => println!("Cancelled; back to the menu"),
+    ///     Err(e) => eprintln!("error: {}", e),
+    /// }
+    /// ```
+    pub fn on_interrupt(mut self, mode: InterruptMode) -> Self {
+        self.on_interrupt = mode;
+        self
+    }
+
+    /// Sets whether the [Input::default_value] is shown in brackets in the prompt (on by
+    /// default).
+    pub fn show_default(mut self, s: bool) -> Self {
+        self.hide_default = !s;
+        self
+    }
+
+    /// Sets how many single-character edits away from an allowed word input may be for
+    /// [Input::wait_allowed] and [Input::wait_allowed_fuzzy] to treat it as a typo.
+    ///
+    /// By default, this is a third of the allowed word's length (and at least 1), so
+    /// longer words tolerate more mistakes. `0` turns suggestions off.
+    pub fn fuzzy_distance(mut self, d: usize) -> Self {
+        self.fuzzy_distance = Some(d);
+        self
+    }
+
+    /// Sets the currency symbol that `wait_money` and `wait_cents` strip from input.
+    ///
+    /// Defaults to `$`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let price = Input::new()
+    ///     .currency("€")
+    ///     .with_reader(&b"12.50 \xe2\x82\xac\n"[..])
+    ///     .wait_cents("Price: ");
+    ///
+    /// assert_eq!(price, 1250);
+    /// ```
+    pub fn currency(mut self, symbol: &str) -> Self {
+        self.currency = Some(symbol.into());
+        self
+    }
+
+    /// Sets whether only the first whitespace-separated word of the input is parsed,
+    /// ignoring anything after it, so `42 extra` reads as `42`.
+    ///
+    /// This applies to every method that parses input with [std::str::FromStr],
+    /// including `String`s, which then only get the first word. Off by default.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let n: i32 = Input::new()
+    ///     .accept_leading(true)
+    ///     .with_reader(&b"42 extra\n"[..])
+    ///     .prompt("Number: ")
+    ///     .wait();
+    ///
+    /// assert_eq!(n, 42);
+    /// ```
+    pub fn accept_leading(mut self, a: bool) -> Self {
+        self.accept_leading = a;
+        self
+    }
+
+    /// Sets a keyword (like `?`) that, when entered, prints `text` and shows the prompt again.
+    ///
+    /// Entering the keyword is never parsed, never counts as invalid input, and never
+    /// triggers `err_msg`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let date: String = Input::new()
+    ///     .help("Dates are written as YYYY-MM-DD", "?")
+    ///     .with_reader(&b"?\n2024-05-01\n"[..])
+    ///     .prompt("Start date (? for help): ")
+    ///     .wait();
+    ///
+    /// assert_eq!(date, "2024-05-01");
+    /// ```
+    /// What the user would see after entering `?`:
+    /// ```plaintext
+    /// Start date (? for help): ?
+    /// Dates are written as YYYY-MM-DD
+    /// Start date (? for help):
+    /// ```
+    pub fn help(mut self, text: &str, keyword: &str) -> Self {
+        self.help = Some((text.into(), keyword.into()));
+        self
+    }
+
+    /// Answers prompts from the lines of the file at `path`, one line per prompt, before
+    /// asking the user.
+    ///
+    /// Every read (`wait`, `read`, `choose`, ...) takes the next line of the file as the
+    /// user's input, echoing it after the prompt, and the usual parsing, `err_msg` and
+    /// `quit` rules apply to it. Once the file's lines run out, reads go back to the
+    /// user; see [Input::interactive_fallback] to stop instead. Clones of this Input
+    /// share the same remaining lines.
+    ///
+    /// Returns an error if the file can't be read.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// # fn main() -> std::io::Result<()> {
+    /// let name = format!("promptis-answers-{}.txt", std::process::id());
+    /// let path = std::env::temp_dir().join(name);
+    /// std::fs::write(&path, "Alice\n30\n")?;
+    ///
+    /// let mut input = Input::new()
+    ///     .answers_file(&path)?
+    ///     .with_reader(&b"Paris\n"[..]);
+    ///
+    /// let name: String = input.prompt("Name: ").wait();
+    /// let age: u32 = input.prompt("Age: ").wait();
+    /// // The file is exhausted, so this one is asked interactively
+    /// let city: String = input.prompt("City: ").wait();
+    ///
+    /// assert_eq!((name.as_str(), age, city.as_str()), ("Alice", 30, "Paris"));
+    /// # std::fs::remove_file(&path)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn answers_file<P: AsRef<Path>>(mut self, path: P) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        let lines = contents.lines().map(str::to_owned).collect();
+        self.answers = Some(Rc::new(RefCell::new(lines)));
+        Ok(self)
+    }
+
+    /// Answers prompts with `answers`, in order, before asking the user, the way
+    /// [Input::answers_file] does with the lines of a file. Handy for demos and tests.
+    ///
+    /// Each answer goes through the usual `quit`, parsing and `err_msg` handling, so an
+    /// invalid one is followed by the next. Once they run out, reads go back to the
+    /// user (or the `with_reader` reader). Calling this again, or after `answers_file`,
+    /// adds to the end of the remaining answers.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .scripted(vec!["Alice".to_string(), "many".to_string(), "30".to_string()])
+    ///     .with_reader(&b"Paris\n"[..]);
+    ///
+    /// let name: String = input.prompt("Name: ").wait();
+    /// // "many" isn't a number, so this takes "30" after it
+    /// let age: u32 = input.prompt("Age: ").wait();
+    /// // The script is used up, so this one comes from the reader
+    /// let city: String = input.prompt("City: ").wait();
+    ///
+    /// assert_eq!((name.as_str(), age, city.as_str()), ("Alice", 30, "Paris"));
+    /// ```
+    pub fn scripted(mut self, answers: Vec<String>) -> Self {
+        self.answers
+            .get_or_insert_with(Default::default)
+            .borrow_mut()
+            .extend(answers);
+        self
+    }
+
+    /// Adds a reader that input is taken from before asking the user, e.g. a file of
+    /// preset answers.
+    ///
+    /// Sources are read in the order they were added: lines come from the first one until
+    /// it reaches its end, then from the next, and so on. They come after any
+    /// `answers_file`, and once every source is exhausted reads go back to the user (see
+    /// [Input::interactive_fallback]). As with `answers_file`, each line taken from a
+    /// source is echoed after the prompt, and clones of this Input share the sources.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .chain_source(Box::new(Cursor::new("Alice\n30\n")))
+    ///     .chain_source(Box::new(Cursor::new("Paris\n")));
+    ///
+    /// let name: String = input.prompt("Name: ").wait();
+    /// let age: u32 = input.prompt("Age: ").wait();
+    /// let city: String = input.prompt("City: ").wait();
+    ///
+    /// assert_eq!((name.as_str(), age, city.as_str()), ("Alice", 30, "Paris"));
+    /// ```
+    ///
+    /// A read that fails with [std::io::ErrorKind::Interrupted] is quietly tried again
+    /// (a few times at most) rather than reported:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::{Cell, RefCell};
+    /// use std::io::{self, BufRead, Cursor, ErrorKind, Read};
+    /// use std::rc::Rc;
+    ///
+    /// // Fails its first read, as if a signal arrived mid-read
+    /// struct Flaky(Cell<bool>, Cursor<&'static str>);
+    ///
+    /// impl Read for Flaky {
+    ///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    ///         self.1.read(buf)
+    ///     }
+    /// }
+    ///
+    /// impl BufRead for Flaky {
+    ///     fn fill_buf(&mut self) -> io::Result<&[u8]> {
+    ///         self.1.fill_buf()
+    ///     }
+    ///     fn consume(&mut self, n: usize) {
+    ///         self.1.consume(n)
+    ///     }
+    ///     fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+    ///         if self.0.replace(false) {
+    ///             return Err(ErrorKind::Interrupted.into());
+    ///         }
+    ///         self.1.read_line(buf)
+    ///     }
+    /// }
+    ///
+    /// let prompts = Rc::new(RefCell::new(0));
+    /// let count = Rc::clone(&prompts);
+    /// let mut input = Input::new()
+    ///     .prompt_decorator(move |p| {
+    ///         *count.borrow_mut() += 1;
+    ///         p.to_owned()
+    ///     })
+    ///     .chain_source(Box::new(Flaky(Cell::new(true), Cursor::new("7\n"))));
+    /// let n: u32 = input.prompt("Number: ").wait();
+    ///
+    /// // Read on the first try, with no error and no second prompt
+    /// assert_eq!((n, *prompts.borrow()), (7, 1));
+    /// ```
+    pub fn chain_source(mut self, source: Box<dyn BufRead>) -> Self {
+        self.sources
+            .get_or_insert_with(|| Sources(Rc::default()))
+            .0
+            .borrow_mut()
+            .push_back(source);
+        self
+    }
+
+    /// Sets whether reads go back to the user once an `answers_file` and every
+    /// `chain_source` run out (the default).
+    ///
+    /// When turned off, running out reads as the end of input, just like stdin being closed.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// let mut input = Input::new()
+    ///     .scripted(vec!["Alice".to_string()])
+    ///     .interactive_fallback(false);
+    ///
+    /// let name: String = input.prompt("Name: ").wait();
+    /// let age = input.prompt("Age: ").try_read::<u32>();
+    ///
+    /// assert_eq!(name, "Alice");
+    /// assert!(matches!(age, Err(InputError::Eof)));
+    /// ```
+    pub fn interactive_fallback(mut self, f: bool) -> Self {
+        self.answers_only = !f;
+        self
+    }
+
+    /// Reads the user's input from `r` instead of stdin.
+    ///
+    /// Unlike a `chain_source`, lines from `r` stand in for the user, so they aren't
+    /// echoed and nothing is read from stdin once `r` runs out. Clones of this Input
+    /// share the reader.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new().with_reader(&b"17\n"[..]);
+    /// let n: u32 = input.prompt("Number: ").wait();
+    ///
+    /// assert_eq!(n, 17);
+    /// assert!(!input.is_interactive());
+    /// ```
+    pub fn with_reader<R: BufRead + 'static>(mut self, r: R) -> Self {
+        self.reader = Some(Source(Rc::new(RefCell::new(r))));
+        self
+    }
+
+    /// Writes everything this Input prints (prompts, menus, error and success messages)
+    /// to `w` instead of stdout. Clones of this Input share the writer.
+    ///
+    /// Since `w` usually isn't a terminal, output written to it is never colored, wrapped
+    /// or animated.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .err_msg("Not a number")
+    ///     .with_reader(&b"many\n3\n"[..]);
+    /// let n: u32 = input.prompt("How many? ").wait();
+    ///
+    /// assert_eq!(n, 3);
+    /// ```
+    ///
+    /// Each prompt is written whole and flushed before anything is read:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::RefCell;
+    /// use std::io::{self, BufReader, Read, Write};
+    /// use std::rc::Rc;
+    ///
+    /// type Log = Rc<RefCell<Vec<String>>>;
+    ///
+    /// struct Out(Log);
+    ///
+    /// impl Write for Out {
+    ///     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    ///         let text = String::from_utf8_lossy(buf);
+    ///         self.0.borrow_mut().push(format!("write {:?}", text));
+    ///         Ok(buf.len())
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         self.0.borrow_mut().push("flush".to_owned());
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// struct In(Log, &'static [u8]);
+    ///
+    /// impl Read for In {
+    ///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    ///         self.0.borrow_mut().push("read".to_owned());
+    ///         self.1.read(buf)
+    ///     }
+    /// }
+    ///
+    /// let log = Log::default();
+    /// let mut input = Input::new()
+    ///     .with_reader(BufReader::new(In(log.clone(), b"3\n")))
+    ///     .with_writer(Out(log.clone()));
+    /// let n: u32 = input.prompt("How many? ").wait();
+    ///
+    /// assert_eq!(n, 3);
+    /// assert_eq!(*log.borrow(), [r#"write "How many? ""#, "flush", "read"]);
+    /// ```
+    pub fn with_writer<W: Write + 'static>(mut self, w: W) -> Self {
+        self.writer = Some(Sink(Rc::new(RefCell::new(w))));
+        self
+    }
+
+    /// Sets whether everything this Input prints (prompts, menus, error and success
+    /// messages) goes to stderr instead of stdout. Defaults to `false`.
+    ///
+    /// This keeps stdout clean for the program's real output, so it can be piped or
+    /// redirected while the user still sees and answers the prompts, as in
+    /// `tool > out.txt`. Coloring, wrapping and the spinner then look at whether stderr
+    /// is a terminal. A `with_writer` writer takes precedence.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let mut input = Input::new().prompt_to_stderr(true);
+    /// let name: String = input.prompt("Name: ").wait();
+    ///
+    /// // Only this ends up in the file, given `tool > greeting.txt`
+    /// println!("Hello, {}!", name);
+    /// ```
+    pub fn prompt_to_stderr(mut self, s: bool) -> Self {
+        self.to_stderr = s;
+        self
+    }
+
+    /// Sets whether `wait_opts` (and `wait_opts_indexed`) skips the question when there's
+    /// only one option, choosing it straight away and saying so. Defaults to `false`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let input = Input::new()
+    ///     .auto_select_single(true);
+    ///
+    /// assert_eq!(input.wait_opts(&["eth0"], "Interface: "), "eth0");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Selected eth0 (the only option)
+    /// ```
+    ///
+    /// Without any options, `wait_opts` panics rather than asking forever:
+    /// ```should_panic
+    /// # use promptis::Input;
+    /// let none: [&str; 0] = [];
+    /// Input::new().auto_select_single(true).wait_opts(&none, "Interface: ");
+    /// ```
+    ///
+    /// [Input::try_wait_opts_indexed] returns an error for them instead.
+    pub fn auto_select_single(mut self, a: bool) -> Self {
+        self.auto_select_single = a;
+        self
+    }
+
+    /// Sets whether `wait_opts` suggests an option when what the user typed looks like a
+    /// typo of one (off by default): `Did you mean 'Second'?` follows the usual error.
+    ///
+    /// A suggestion is only made when just one option is close enough, by the same
+    /// measure as [Input::wait_allowed], which [Input::fuzzy_distance] can change.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new()
+    ///     .suggestions(true)
+    ///     .chain_source(Box::new(Cursor::new("Secnd\n2\n")));
+    ///
+    /// assert_eq!(input.wait_opts(&["First", "Second"], "Pick: "), "Second");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// 1. First
+    /// 2. Second
+    /// Pick: Secnd
+    /// Please enter a number within the bounds 1..=2
+    /// Did you mean 'Second'?
+    /// 1. First
+    /// 2. Second
+    /// Pick: 2
+    /// ```
+    pub fn suggestions(mut self, s: bool) -> Self {
+        self.suggestions = s;
+        self
+    }
+
+    /// Sets how many options `wait_opts` shows at once, for long lists that would
+    /// otherwise scroll off the screen. Defaults to `0`, which shows them all.
+    ///
+    /// With more options than fit on one page, the user can enter `n` or `p` to move to
+    /// the next or previous page, unless that also picks an option (such as one called
+    /// "N", or a letter with [OptionStyle::Lettered]). Options keep their numbers across
+    /// pages, so any of them can be picked by number (or by name) from any page, and the
+    /// choice is returned just as without paging.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let input = Input::new()
+    ///     .page_size(2)
+    ///     .with_reader(&b"n\nn\n5\n"[..]);
+    /// let planets = ["Mercury", "Venus", "Earth", "Mars", "Jupiter"];
+    ///
+    /// assert_eq!(input.wait_opts_indexed(&planets, "Planet: "), (4, "Jupiter"));
+    /// ```
+    /// What the user sees at first:
+    /// ```plaintext
+    /// 1. Mercury
+    /// 2. Venus
+    /// Page 1 of 3 (n: next, p: previous)
+    /// Planet: n
+    /// 3. Earth
+    /// 4. Mars
+    /// Page 2 of 3 (n: next, p: previous)
+    /// Planet:
+    /// ```
+    pub fn page_size(mut self, n: usize) -> Self {
+        self.page_size = n;
+        self
+    }
+
+    /// Sets how [Input::wait_opts] (with `wait_opts_indexed`, `wait_opts_labeled` and
+    /// [Input::render_opts]) labels its options: numbered from one (the default), from
+    /// zero, lettered, or however a closure likes. The user picks an option by typing its
+    /// label, or the option itself as usual.
+    ///
+    /// With a [page_size](Input::page_size), `n` and `p` only turn the pages when they
+    /// aren't the label of an option, so with fourteen or more lettered options `n`
+    /// picks the fourteenth.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, OptionStyle};
+    /// use std::io::Cursor;
+    /// use std::rc::Rc;
+    ///
+    /// let sizes = ["Small", "Medium", "Large"];
+    /// let input = Input::new()
+    ///     .option_style(OptionStyle::Lettered)
+    ///     .chain_source(Box::new(Cursor::new("3\nB\n")));
+    ///
+    /// assert_eq!(input.render_opts(&sizes), "a) Small\nb) Medium\nc) Large\n");
+    /// // "3" isn't one of the letters, so the user is asked again
+    /// assert_eq!(input.wait_opts(&sizes, "Size: "), "Medium");
+    ///
+    /// let bullets = Input::new().option_style(OptionStyle::Custom(Rc::new(|i, text| {
+    ///     format!("  [{}] {}", i + 1, text)
+    /// })));
+    /// assert_eq!(bullets.render_opts(&sizes[..2]), "  [1] Small\n  [2] Medium\n");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// a) Small
+    /// b) Medium
+    /// c) Large
+    /// Size: 3
+    /// Please enter a letter from a to c
+    /// a) Small
+    /// b) Medium
+    /// c) Large
+    /// Size: B
+    /// ```
+    ///
+    /// With lettered pages, `n` picks the fourteenth option rather than turning the page:
+    /// ```
+    /// # use promptis::{Input, OptionStyle};
+    /// let items: Vec<String> = (1..=16).map(|i| format!("Item {}", i)).collect();
+    /// let input = Input::new()
+    ///     .option_style(OptionStyle::Lettered)
+    ///     .page_size(5)
+    ///     .with_reader(&b"n\n"[..]);
+    ///
+    /// let (index, item) = input.wait_opts_indexed(&items, "Item: ");
+    /// assert_eq!((index, item.as_str()), (13, "Item 14"));
+    /// ```
+    pub fn option_style(mut self, style: OptionStyle) -> Self {
+        self.option_style = style;
+        self
+    }
+
+    /// Sets whether `wait_list` silently skips entries that don't parse, instead of
+    /// printing `err_msg` for them.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let numbers: Vec<i32> = Input::new()
+    ///     .list_skip_invalid(true)
+    ///     .with_reader(&b"1\ntwo\n2\n\n"[..])
+    ///     .wait_list("Numbers:\n");
+    ///
+    /// assert_eq!(numbers, [1, 2]);
+    /// ```
+    pub fn list_skip_invalid(mut self, s: bool) -> Self {
+        self.list_skip_invalid = s;
+        self
+    }
+
+    /// Sets whether long prompts are word-wrapped to the terminal's width (the default
+    /// with the `tty` feature).
+    ///
+    /// Wrapping only happens when stdout is a terminal whose width can be detected;
+    /// otherwise prompts are printed unchanged. It's applied before `prompt_decorator`.
+    #[cfg(feature = "tty")]
+    pub fn wrap(mut self, w: bool) -> Self {
+        self.no_wrap = !w;
+        self
+    }
+
+    /// Records every interaction with the user to `w` as newline-delimited JSON.
+    ///
+    /// Each `wait`-style call, `read`, `read_cow` and `choose_or_quit` writes one object
+    /// once it finishes, with these fields:
+    /// - `prompt`: the prompt as given (before any decoration)
+    /// - `raw_input`: the last line the user entered
+    /// - `parsed`: the text that was accepted and parsed, or `null` if nothing was
+    /// - `attempts`: how many lines it took
+    /// - `quit`: whether the `soft_quit` trigger was entered
+    ///
+    /// A `quit` trigger that ends the program does so before anything is written.
+    /// Write errors are ignored so a failing transcript never interrupts the prompt.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// # fn main() -> std::io::Result<()> {
+    /// let dir = std::env::temp_dir();
+    /// let answers = dir.join(format!("promptis-json-answers-{}.txt", std::process::id()));
+    /// let log = dir.join(format!("promptis-json-transcript-{}.ndjson", std::process::id()));
+    /// std::fs::write(&answers, "abc\n42\nok\n")?;
+    ///
+    /// let mut input = Input::new()
+    ///     .answers_file(&answers)?
+    ///     .json_transcript(std::fs::File::create(&log)?);
+    /// let n: i32 = input.prompt("Number: ").wait();
+    /// let s: Option<String> = input.prompt("Word: ").read();
+    /// drop(input);
+    ///
+    /// let lines: Vec<String> = std::fs::read_to_string(&log)?.lines().map(String::from).collect();
+    /// assert_eq!(lines, [
+    ///     r#"{"prompt":"Number: ","raw_input":"42","parsed":"42","attempts":2,"quit":false}"#,
+    ///     r#"{"prompt":"Word: ","raw_input":"ok","parsed":"ok","attempts":1,"quit":false}"#,
+    /// ]);
+    /// # std::fs::remove_file(&answers)?;
+    /// # std::fs::remove_file(&log)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn json_transcript<W: Write + 'static>(mut self, w: W) -> Self {
+        self.transcript = Some(Sink(Rc::new(RefCell::new(w))));
+        self
+    }
+
+    /// Sets whether prompts and messages are colored.
+    ///
+    /// Prompts use [Input::prompt_color] (the terminal's default color unless set),
+    /// error messages use [Input::error_color] (red unless set), and the
+    /// [Input::success_msg] uses [Input::success_color] (green unless set).
+    ///
+    /// Even when turned on, nothing is colored if stdout isn't a terminal or the
+    /// `NO_COLOR` environment variable is set; see [Input::force_color] to color anyway.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::{Color, Input};
+    /// let n: u32 = Input::new()
+    ///     .color(true)
+    ///     .prompt_color(Color::Cyan)
+    ///     .err_msg("Not a number")
+    ///     .prompt("Number: ")
+    ///     .wait();
+    /// ```
+    pub fn color(mut self, c: bool) -> Self {
+        self.color = c;
+        self
+    }
+
+    /// Sets whether prompts and messages are colored no matter where the output goes
+    /// (off by default), e.g. for piping into a pager that understands color. This turns
+    /// coloring on like [Input::color], but skips its checks for a terminal and
+    /// `NO_COLOR`, and also colors output sent to a `with_writer` writer.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Color, Input};
+    /// let mut input = Input::new()
+    ///     .force_color(true)
+    ///     .prompt_color(Color::Cyan)
+    ///     .with_reader(&b"7\n"[..]);
+    /// let n: u32 = input.prompt("Number: ").wait();
+    ///
+    /// assert_eq!(n, 7);
+    /// ```
+    pub fn force_color(mut self, f: bool) -> Self {
+        self.force_color = f;
+        self
+    }
+
+    /// Sets the color of prompts, when [Input::color] is on.
+    pub fn prompt_color(mut self, c: Color) -> Self {
+        self.prompt_color = Some(c);
+        self
+    }
+
+    /// Sets the color of error messages, when [Input::color] is on.
+    pub fn error_color(mut self, c: Color) -> Self {
+        self.error_color = Some(c);
+        self
+    }
+
+    /// Sets the color of the [Input::success_msg], when [Input::color] is on.
+    pub fn success_color(mut self, c: Color) -> Self {
+        self.success_color = Some(c);
+        self
+    }
+
+    /// Sets a message that will be displayed to the user once they enter something valid.
+    pub fn success_msg(mut self, m: &str) -> Self {
+        self.success_msg = Some(m.into());
+        self
+    }
+
+    /// Sets whether each answer `wait` (or `try_wait`, ...) accepts is repeated back on a
+    /// line of its own after the prompt, like `✓ Name: Alice`, leaving a record of every
+    /// answer when a long form scrolls by. For [Input::wait_opts] it's the chosen option.
+    /// Secret input is never repeated. Defaults to `false`.
+    ///
+    /// The line goes wherever the prompts do, colored like the `success_msg` if a
+    /// [success_color](Input::success_color) is set.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .echo(true)
+    ///     .with_reader(&b"Alice\n2\n"[..]);
+    /// let name: String = input.prompt("Enter your name: ").wait();
+    /// let team = input.wait_opts(&["Red", "Blue"], "Team: ");
+    ///
+    /// assert_eq!((name.as_str(), team), ("Alice", "Blue"));
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Enter your name: Alice
+    /// ✓ Enter your name: Alice
+    /// 1. Red
+    /// 2. Blue
+    /// Team: 2
+    /// ✓ Team: Blue
+    /// ```
+    pub fn echo(mut self, e: bool) -> Self {
+        self.echo_answers = e;
+        self
+    }
+
+    /// Sets whether an empty line is printed after each line of input is read. Defaults
+    /// to `false`.
+    ///
+    /// Some terminals leave the cursor right after the input instead of on a new line,
+    /// which runs whatever's printed next into the prompt; this evens that out.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .println_after(true)
+    ///     .chain_source(Box::new(Cursor::new("Ada\n")));
+    /// let name: String = input.prompt("Name: ").wait();
+    /// println!("Hello, {}!", name);
+    /// ```
+    /// The output, with the extra line only present because of `println_after`:
+    /// ```plaintext
+    /// Name: Ada
+    ///
+    /// Hello, Ada!
+    /// ```
+    pub fn println_after(mut self, p: bool) -> Self {
+        self.println_after = p;
+        self
+    }
+
+    /// Sets whether the prompt is only shown for the first read, which keeps the output
+    /// clean when reading many values, one per line, from a pipe. Defaults to `false`.
+    ///
+    /// Later reads (including asking again after invalid input) show nothing until
+    /// [Input::reset] or [Input::clear_prompt] is called, even if the prompt text is
+    /// changed.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .prompt_once(true)
+    ///     .with_reader(&b"1\n2\n3\n4\n"[..]);
+    /// input.prompt("Values, one per line: ");
+    ///
+    /// let values: Vec<i32> = (0..3).map(|_| input.wait()).collect();
+    /// assert_eq!(values, [1, 2, 3]);
+    ///
+    /// input.reset();
+    /// assert_eq!(input.prompt("One more: ").wait::<i32>(), 4);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Values, one per line: 1
+    /// 2
+    /// 3
+    /// One more: 4
+    /// ```
+    pub fn prompt_once(mut self, p: bool) -> Self {
+        self.prompt_once = p;
+        self
+    }
+
+    /// Sets whether `wait` (and the other methods that ask until they get a usable
+    /// answer) shows the prompt again after the error message for a bad answer (on by
+    /// default), so the question stays in view. Turned off, the user just types the next
+    /// answer after the error message.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .err_msg("Not a number, try again:")
+    ///     .with_reader(&b"ten\n10\n"[..]);
+    ///
+    /// let n: u32 = input.prompt("Count: ").wait();
+    /// assert_eq!(n, 10);
+    ///
+    /// let mut input = input.reprompt(false).with_reader(&b"ten\n10\n"[..]);
+    /// let n: u32 = input.prompt("Count: ").wait();
+    /// assert_eq!(n, 10);
+    /// ```
+    /// What the user sees with `reprompt(false)`:
+    /// ```plaintext
+    /// Count: ten
+    /// Not a number, try again:
+    /// 10
+    /// ```
+    pub fn reprompt(mut self, r: bool) -> Self {
+        self.no_reprompt = !r;
+        self
+    }
+
+    /// Starts this Input over for its next question. This clears:
+    ///
+    /// - the prompt (see [Input::clear_prompt]), and with [Input::prompt_once], the next
+    ///   read shows the new one again
+    /// - checks on the value: [Input::validate], [Input::min] and [Input::max], and
+    ///   [Input::require_canonical]
+    /// - defaults: [Input::default_value], [Input::default_fn] and a remembered
+    ///   `sticky_default` answer
+    ///
+    /// Everything else, such as the quit trigger, `err_msg` and where input comes from,
+    /// is kept.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .quit("quit")
+    ///     .max(10)
+    ///     .default_value(5)
+    ///     .chain_source(Box::new(Cursor::new("\n42\n")));
+    ///
+    /// let n: i32 = input.prompt("Small number: ").wait();
+    /// assert_eq!(n, 5);
+    ///
+    /// input.reset();
+    /// let n: i32 = input.prompt("Any number: ").wait();
+    /// assert_eq!(n, 42);
+    /// ```
+    pub fn reset(&mut self) {
+        self.clear_prompt();
+        self.validator = None;
+        self.bounds = None;
+        self.bounds_check = None;
+        self.canonical = None;
+        self.default_value = None;
+        self.default_fn = None;
+        self.sticky_value.take();
+    }
+
+    /// Clears the prompt set with [Input::prompt], so that the next read shows none
+    /// rather than the last one, and re-arms [Input::prompt_once]. Nothing else changes.
+    pub fn clear_prompt(&mut self) {
+        self.user_prompt.clear();
+        self.prompted.set(false);
+    }
+
+    /// Sets the prompt [Input::choose_with_reason] asks for a reason with. Defaults to
+    /// `"Reason (optional): "`.
+    pub fn reason_prompt(mut self, p: &str) -> Self {
+        self.reason_prompt = Some(p.to_owned());
+        self
+    }
+
+    /// Sets whether [Input::choose_with_reason] asks for a reason after "yes" instead of
+    /// after "no". Defaults to `false`.
+    pub fn reason_on_yes(mut self, r: bool) -> Self {
+        self.reason_on_yes = r;
+        self
+    }
+
+    /// Sets how many times in a row the same invalid input may be entered before
+    /// [Input::try_wait] gives up with [InputError::Repeated]. Defaults to `0`, which
+    /// never gives up.
+    ///
+    /// This catches a stuck key or a script feeding the same bad line forever, while
+    /// someone trying different (still wrong) answers is asked again as usual. Only the
+    /// `try_` methods give up; `wait` keeps asking.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .abort_on_repeat(3)
+    ///     .chain_source(Box::new(Cursor::new("x\ny\ny\ny\n7\n")));
+    /// let result = input.prompt("Number: ").try_wait::<i32>();
+    ///
+    /// assert!(matches!(result, Err(InputError::Repeated { input, times: 3 }) if input == "y"));
+    /// ```
+    pub fn abort_on_repeat(mut self, n: usize) -> Self {
+        self.abort_repeats = n;
+        self
+    }
+
+    /// Sets whether what the user types is hidden, as for a password. Defaults to `false`.
+    ///
+    /// When stdin is a terminal (on Linux and macOS), its echo is turned off while each
+    /// line is read and turned back on as soon as Enter is pressed, before the input is
+    /// parsed or checked for the `quit` trigger, or if Ctrl-C ends the program. Editing
+    /// the line, like backspace, still works. Lines from a `chain_source` or
+    /// `answers_file` aren't shown either, and a `json_transcript` records the input as
+    /// empty. A secret is never kept as the default by `sticky_default` or `remember`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .secret(true)
+    ///     .sticky_default(true)
+    ///     .chain_source(Box::new(Cursor::new("hunter2\nswordfish\n")));
+    /// let password: String = input.prompt("Password: ").wait();
+    ///
+    /// assert_eq!(password, "hunter2");
+    ///
+    /// // Asking again doesn't offer the last password as the default
+    /// let again: String = input.wait();
+    /// assert_eq!(again, "swordfish");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Password:
+    /// Password:
+    /// ```
+    pub fn secret(self, s: bool) -> Self {
+        self.hidden.set(s);
+        self
+    }
+
+    /// Sets the smallest value `wait` and `read` accept (inclusive), along with the other
+    /// methods listed on [Input::validate]. Anything smaller is rejected with a message
+    /// like `0 must be at least 1`, and `wait` asks again.
+    ///
+    /// The bound only applies when reading values of the same type as `m`, so a literal
+    /// may need a suffix, as in `min(1u16)` for `wait::<u16>()`. Setting a `min` of a
+    /// different type than the `max` replaces both.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .min(1u8)
+    ///     .max(10u8)
+    ///     .chain_source(Box::new(Cursor::new("0\n11\n10\n")));
+    /// let rating: u8 = input.prompt("Rating: ").wait();
+    ///
+    /// // 0 and 11 were out of range, but the bounds themselves are allowed
+    /// assert_eq!(rating, 10);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Rating: 0
+    /// 0 must be between 1 and 10
+    /// Rating: 11
+    /// 11 must be between 1 and 10
+    /// Rating: 10
+    /// ```
+    pub fn min<T>(self, m: T) -> Self
+    where
+        T: std::str::FromStr + PartialOrd + fmt::Display + Clone + 'static,
+    {
+        let mut bounds = self.bounds_of::<T>();
+        bounds.min = Some(m);
+        self.set_bounds(bounds)
+    }
+
+    /// Sets the largest value `wait` and `read` accept (inclusive). Anything larger is
+    /// rejected with a message like `11 must be at most 10`, and `wait` asks again.
+    ///
+    /// As with [Input::min], the bound only applies to values of the same type as `m`,
+    /// read by the methods listed on [Input::validate].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// // With only a `max`, there's no lower limit
+    /// let mut input = Input::new()
+    ///     .max(0.5)
+    ///     .chain_source(Box::new(Cursor::new("0.75\n-3.5\n")));
+    ///
+    /// assert_eq!(input.prompt("Ratio: ").read::<f64>(), None);
+    /// assert_eq!(input.prompt("Ratio: ").read::<f64>(), Some(-3.5));
+    /// ```
+    pub fn max<T>(self, m: T) -> Self
+    where
+        T: std::str::FromStr + PartialOrd + fmt::Display + Clone + 'static,
+    {
+        let mut bounds = self.bounds_of::<T>();
+        bounds.max = Some(m);
+        self.set_bounds(bounds)
+    }
+
+    /// Makes `bounds` the ones `wait` and `read` check values of type `T` against
+    fn set_bounds<T>(mut self, bounds: parse::Bounds<T>) -> Self
+    where
+        T: std::str::FromStr + Clone + 'static,
+    {
+        let check = bounds.clone();
+        self.bounds = Some(Callback(Rc::new(bounds)));
+        self.bounds_check = Some(Check::new(move |_, _, value: T| {
+            check.check(&value).map_or(Ok(()), |e| Err(Some(e)))
+        }));
+        self
+    }
+
+    /// Sets how many invalid answers [Input::try_wait] accepts before giving up with
+    /// [InputError::Exhausted]. Defaults to `0`, which never gives up.
+    ///
+    /// Only input that can't be used counts: a valid answer ends the read as usual,
+    /// and with `soft_quit` the quit trigger gives [InputError::Quit]. The error message
+    /// for the last invalid answer is still shown. As with [Input::abort_on_repeat], only
+    /// the `try_` methods give up; `wait` keeps asking.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .max_attempts(3)
+    ///     .chain_source(Box::new(Cursor::new("one\ntwo\nthree\n4\n")));
+    /// input.prompt("Number: ");
+    ///
+    /// assert!(matches!(input.try_wait::<i32>(), Err(InputError::Exhausted { attempts: 3 })));
+    /// // Each read gets its own attempts
+    /// assert!(matches!(input.try_wait::<i32>(), Ok(4)));
+    /// ```
+    pub fn max_attempts(mut self, n: usize) -> Self {
+        self.max_attempts = n;
+        self
+    }
+
+    /// Makes each read take several lines, up to a line consisting of just `terminator`
+    /// (or the end of input), like a commit message. Only the first line is prompted for.
+    ///
+    /// The lines are parsed together as one value. Line breaks between them are kept,
+    /// while the terminator line and the line break before it aren't (and, as usual for
+    /// `wait` and `read`, leading and trailing whitespace is trimmed). The `quit`
+    /// trigger only counts when entered as the first line.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "Fix the parser\n\nIt choked on empty lines.\n.\n";
+    /// let mut input = Input::new()
+    ///     .multiline(".")
+    ///     .chain_source(Box::new(Cursor::new(lines)));
+    /// let message: String = input.prompt("Message (end with .):\n").wait();
+    ///
+    /// assert_eq!(message, "Fix the parser\n\nIt choked on empty lines.");
+    /// ```
+    pub fn multiline(mut self, terminator: &str) -> Self {
+        self.multiline = Some(terminator.to_owned());
+        self
+    }
+
+    /// Sets whether control characters are stripped from input before it's used, which
+    /// helps with messy pasted text.
+    ///
+    /// This removes ANSI escape sequences (CSI sequences like `ESC [ 31 m`, OSC sequences
+    /// like terminal title changes, and any other `ESC` plus the character after it), and
+    /// every other Unicode control character except tabs and line endings. It happens
+    /// before anything else looks at the input, including the `quit` trigger.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let pasted = "\x1b[1;31m4\x082\x1b[0m\n";
+    /// let mut input = Input::new()
+    ///     .sanitize_input(true)
+    ///     .chain_source(Box::new(Cursor::new(pasted)));
+    /// let n: i32 = input.prompt("Number: ").wait();
+    ///
+    /// assert_eq!(n, 42);
+    /// ```
+    pub fn sanitize_input(mut self, s: bool) -> Self {
+        self.sanitize = s;
+        self
+    }
+
+    /// Waits until the user responds with something that can be parsed to `T`.
+    ///
+    /// If a `quit` trigger has been set and later read from the user, will exit early
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let data: i32 = Input::new()
+    ///     .prompt("Enter a number: ")
+    ///     .wait();
+    ///
+    /// println!("Your number is {}", data);
+    /// ```
+    ///
+    /// If the prompt can't be shown because stdout is a pipe whose reader has gone away,
+    /// as in `prog | head`, the program ends quietly instead of asking forever:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::process::{Command, Stdio};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// if std::env::var_os("PROMPTIS_BROKEN_PIPE").is_some() {
+    ///     // Nothing to read and nowhere to write: just keeps asking until stdout fails
+    ///     let _: i32 = Input::new().prompt("Number: ").wait();
+    ///     unreachable!();
+    /// }
+    ///
+    /// let mut child = Command::new(std::env::current_exe().unwrap())
+    ///     .env("PROMPTIS_BROKEN_PIPE", "1")
+    ///     .stdin(Stdio::null())
+    ///     .stdout(Stdio::piped())
+    ///     .spawn()
+    ///     .unwrap();
+    /// drop(child.stdout.take());
+    ///
+    /// let start = Instant::now();
+    /// let status = loop {
+    ///     if let Some(status) = child.try_wait().unwrap() {
+    ///         break status;
+    ///     }
+    ///     if start.elapsed() > Duration::from_secs(10) {
+    ///         child.kill().unwrap();
+    ///         panic!("still prompting into a closed pipe");
+    ///     }
+    ///     std::thread::sleep(Duration::from_millis(10));
+    /// };
+    /// assert!(status.success());
+    /// ```
+    pub fn wait<T>(&self) -> T
+    where
+        T: std::str::FromStr,
+    {
+        self.wait_raw(&self.user_prompt, |s| self.parse_valid(self.strip(s)))
+    }
+
+    /// Like [Input::wait], but the trimmed input is parsed by `parser` instead of
+    /// [FromStr](std::str::FromStr), for types that don't implement it or need parsing
+    /// of their own. Input `parser` turns down (by returning `None`) gets `err_msg` and
+    /// another prompt, and the quit trigger works as usual.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new().chain_source(Box::new(Cursor::new("0xZZ\n0xff\n")));
+    /// let hex = |s: &str| u32::from_str_radix(s.strip_prefix("0x")?, 16).ok();
+    /// let mask = input.prompt("Mask: ").wait_with(hex);
+    ///
+    /// assert_eq!(mask, 255);
+    /// ```
+    pub fn wait_with<T, F>(&self, parser: F) -> T
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        self.wait_raw(&self.user_prompt, |s| parser(self.strip(s)).ok_or(None))
+    }
+
+    /// Like [Input::wait], but gives up with an [InputError] instead of asking forever
+    /// when input can't be used: when input ends ([InputError::Eof]), reading it or
+    /// showing the prompt fails ([InputError::Io]), or as configured by
+    /// [Input::abort_on_repeat] and [Input::max_attempts].
+    ///
+    /// With [Input::soft_quit], the quit trigger gives [InputError::Quit] rather than
+    /// another prompt.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let input = Input::new().abort_on_repeat(5);
+    ///
+    /// match input.try_wait::<u16>() {
+    ///     Ok(port) => println!("Listening on {}", port),
+    ///     Err(e) => eprintln!("Giving up: {}", e),
+    /// }
+    /// ```
+    ///
+    /// Where `wait` would keep asking once piped input runs out, `try_wait` stops:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// let mut input = Input::new().with_reader(&b"oops\n"[..]);
+    /// let result = input.prompt("Port: ").try_wait::<u16>();
+    ///
+    /// assert!(matches!(result, Err(InputError::Eof)));
+    /// ```
+    ///
+    /// An IO error, such as failing to write the prompt, is returned too, where `wait`
+    /// would print it and carry on:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// use std::io::{self, Write};
+    ///
+    /// struct Broken;
+    ///
+    /// impl Write for Broken {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(io::Error::other("disk full"))
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut input = Input::new().with_reader(&b"8080\n"[..]).with_writer(Broken);
+    /// let result = input.prompt("Port: ").try_wait::<u16>();
+    ///
+    /// assert!(matches!(result, Err(InputError::Io(e)) if e.to_string() == "disk full"));
+    /// ```
+    ///
+    /// That includes a broken pipe (the reader of the output going away, as with
+    /// `prog | head`), where `wait` quietly ends the program:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// use std::io::{self, ErrorKind, Write};
+    ///
+    /// struct Closed;
+    ///
+    /// impl Write for Closed {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(ErrorKind::BrokenPipe.into())
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut input = Input::new().with_reader(&b"8080\n"[..]).with_writer(Closed);
+    /// let result = input.prompt("Port: ").try_wait::<u16>();
+    ///
+    /// assert!(matches!(result, Err(InputError::Io(e)) if e.kind() == ErrorKind::BrokenPipe));
+    /// ```
+    pub fn try_wait<T>(&self) -> Result<T, InputError>
+    where
+        T: std::str::FromStr,
+    {
+        self.try_wait_raw(&self.user_prompt, |s| self.parse_valid(self.strip(s)))
+    }
+
+    /// Like [Input::wait], but the quit trigger returns `Err(Quit)` instead of ending the
+    /// program, whether or not `soft_quit` is set, so the caller can clean up first.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, Quit};
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .quit("quit")
+    ///     .chain_source(Box::new(Cursor::new("12\n  quit \n")));
+    ///
+    /// assert_eq!(input.prompt("Number: ").wait_or_quit::<u32>(), Ok(12));
+    /// // Compared just like the trigger for `wait`, ignoring surrounding whitespace
+    /// assert_eq!(input.prompt("Number: ").wait_or_quit::<u32>(), Err(Quit));
+    /// assert!(input.was_quit());
+    /// ```
+    pub fn wait_or_quit<T>(&self) -> Result<T, Quit>
+    where
+        T: std::str::FromStr,
+    {
+        let held = self.hold_quit.replace(true);
+        let response = self.wait_loop(
+            &self.user_prompt,
+            |s| self.parse_valid(self.strip(s)),
+            false,
+        );
+        self.hold_quit.set(held);
+        response.map_err(|_| Quit)
+    }
+
+    /// Waits until the user responds with something that `parse` accepts, handing
+    /// `parse` the external context `ctx` alongside the input.
+    ///
+    /// This is useful when validation depends on runtime data, e.g. a registry of
+    /// valid IDs. `quit` triggers and `err_msg` behave exactly as they do for `wait`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::collections::HashSet;
+    ///
+    /// let allowed: HashSet<u32> = [100, 200, 300].into_iter().collect();
+    /// // 42 parses, but isn't in the registry, so the user is asked again
+    /// let id: u32 = Input::new()
+    ///     .err_msg("Unknown ID; please retry")
+    ///     .with_reader(&b"42\n200\n"[..])
+    ///     .wait_with_ctx(&allowed, "Enter an ID: ", |ids, s| {
+    ///         s.parse().ok().filter(|id| ids.contains(id))
+    ///     });
+    ///
+    /// assert_eq!(id, 200);
+    /// ```
+    pub fn wait_with_ctx<T, C, F>(&self, ctx: &C, p: &str, parse: F) -> T
+    where
+        C: ?Sized,
+        F: Fn(&C, &str) -> Option<T>,
+    {
+        self.wait_parsed(p, |s| parse(ctx, s))
+    }
+
+    /// Waits until the user responds with a line that can be sliced into columns
+    /// of the given `widths`, returning each column's trimmed contents.
+    ///
+    /// Widths are counted in characters. The line counts as too short (and the user
+    /// is re-prompted) if it doesn't reach the last column; the last column itself
+    /// may be partially filled. Anything past the sum of `widths` is returned as
+    /// one extra trailing segment.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// // "A100" stops short of the last column, so the user is asked again
+    /// let cols = Input::new()
+    ///     .with_reader(&b"A100\nA100  Widget    42 pcs\n"[..])
+    ///     .wait_columns(&[6, 10, 3], "Record: ");
+    ///
+    /// assert_eq!(cols, ["A100", "Widget", "42", "pcs"]);
+    /// ```
+    pub fn wait_columns(&self, widths: &[usize], p: &str) -> Vec<String> {
+        self.wait_raw(p, |s| parse::split_columns(s, widths).ok_or(None))
+    }
+
+    /// Waits until the user responds with a non-zero value that can be parsed to `T`,
+    /// e.g. one of the [std::num::NonZeroU32] family.
+    ///
+    /// Any spelling of zero (`0`, `-0`, `000`) is rejected with a message saying so,
+    /// rather than the generic `err_msg`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::num::NonZeroU32;
+    ///
+    /// let divisor: NonZeroU32 = Input::new()
+    ///     .err_msg("Not a number; please retry")
+    ///     .with_reader(&b"000\nfive\n5\n"[..])
+    ///     .wait_nonzero("Divide by: ");
+    ///
+    /// assert_eq!(divisor.get(), 5);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Divide by: 000
+    /// Value must not be zero
+    /// Divide by: five
+    /// Not a number; please retry
+    /// Divide by: 5
+    /// ```
+    pub fn wait_nonzero<T>(&self, p: &str) -> T
+    where
+        T: std::str::FromStr,
+    {
+        self.wait_raw(p, |s| {
+            let s = s.trim();
+            let digits = s.trim_start_matches(['+', '-']);
+            if !digits.is_empty() && digits.chars().all(|c| c == '0') {
+                Err(Some("Value must not be zero".to_owned()))
+            } else {
+                self.parse_valid(s)
+            }
+        })
+    }
+
+    /// Prompts with `p` for values one after another until the user enters one equal to
+    /// `sentinel`, returning the values before it, in order.
+    ///
+    /// Each value is read as by `wait`, so an entry that can't be used shows `err_msg`
+    /// and is left out. The sentinel is compared after parsing, so for numbers `0`,
+    /// `00` and `+0` all finish the list.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new()
+    ///     .err_msg("Please enter a whole number")
+    ///     .chain_source(Box::new(Cursor::new("12\nabc\n-3\n0\n8\n")));
+    /// let scores: Vec<i32> = input.wait_until_value("Score (0 to finish): ", 0);
+    ///
+    /// assert_eq!(scores, [12, -3]);
+    /// ```
+    pub fn wait_until_value<T>(&self, p: &str, sentinel: T) -> Vec<T>
+    where
+        T: std::str::FromStr + PartialEq,
+    {
+        let mut items = Vec::new();
+        loop {
+            let item: T = self.wait_parsed_msg(p, |s| self.parse_valid(s));
+            if item == sentinel {
+                return items;
+            }
+            items.push(item);
+        }
+    }
+
+    /// Waits until the user enters a value no greater than `bound`, which is useful when
+    /// the bound was itself asked for earlier. Larger values are rejected with a message
+    /// like `11 must be at most 10`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new().chain_source(Box::new(Cursor::new("10\n11\n10\n")));
+    /// let max: u32 = input.prompt("Seats available: ").wait();
+    /// let seats = input.wait_leq(max, "Seats to book: ");
+    ///
+    /// // 11 was rejected, but the bound itself is fine
+    /// assert_eq!(seats, 10);
+    /// ```
+    pub fn wait_leq<T>(&self, bound: T, p: &str) -> T
+    where
+        T: std::str::FromStr + PartialOrd + fmt::Display,
+    {
+        let mut bounds = parse::Bounds::new();
+        bounds.max = Some(bound);
+        self.wait_bounded(p, &bounds)
+    }
+
+    /// Waits until the user enters a value no less than `bound`, the counterpart of
+    /// [Input::wait_leq]. Smaller values are rejected with a message like
+    /// `4 must be at least 5`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("4.99\n5\n")));
+    ///
+    /// assert_eq!(input.wait_geq(5.0, "Bid (at least 5): "), 5.0);
+    /// ```
+    pub fn wait_geq<T>(&self, bound: T, p: &str) -> T
+    where
+        T: std::str::FromStr + PartialOrd + fmt::Display,
+    {
+        let mut bounds = parse::Bounds::new();
+        bounds.min = Some(bound);
+        self.wait_bounded(p, &bounds)
+    }
+
+    /// Displays `p` until the user enters a value within `bounds`
+    fn wait_bounded<T>(&self, p: &str, bounds: &parse::Bounds<T>) -> T
+    where
+        T: std::str::FromStr,
+    {
+        self.wait_parsed_msg(p, |s| {
+            let value = self.parse_valid(s)?;
+            match bounds.check(&value) {
+                Some(e) => Err(Some(e)),
+                None => Ok(value),
+            }
+        })
+    }
+
+    /// Waits until the user enters something shaped like an email address, returning it
+    /// trimmed.
+    ///
+    /// The check is intentionally loose and doesn't follow the full RFC 5322 grammar:
+    /// it only asks for exactly one `@`, something before it, and a domain after it
+    /// containing a dot (not at its start or end), with no spaces anywhere. Whether the
+    /// address exists is for the caller to find out.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// // A missing "@" and an empty domain are both asked again
+    /// let lines = "ada.example.com\nada@\n ada@example.com \n";
+    /// let input = Input::new().chain_source(Box::new(Cursor::new(lines)));
+    ///
+    /// assert_eq!(input.wait_email("Email: "), "ada@example.com");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Email: ada.example.com
+    /// 'ada.example.com' isn't a valid email address
+    /// Email: ada@
+    /// 'ada@' isn't a valid email address
+    /// Email:  ada@example.com
+    /// ```
+    pub fn wait_email(&self, p: &str) -> String {
+        self.wait_parsed_msg(p, |s| match parse::is_email(s) {
+            true => Ok(s.to_owned()),
+            false => Err(Some(format!("'{}' isn't a valid email address", s))),
+        })
+    }
+
+    /// Waits until the user enters a port number, from 1 to 65535.
+    ///
+    /// With [Input::warn_privileged] set, a port below 1024 is still accepted, but
+    /// followed by a line warning that binding it usually needs elevated privileges.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("0\n70000\n8080\n")));
+    ///
+    /// assert_eq!(input.wait_port("Port: "), 8080);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Port: 0
+    /// Port 0 is reserved; please enter a port from 1 to 65535
+    /// Port: 70000
+    /// Please enter a port from 1 to 65535
+    /// Port: 8080
+    /// ```
+    pub fn wait_port(&self, p: &str) -> u16 {
+        let port = self.wait_parsed_msg(p, |s| match s.parse::<u32>() {
+            Ok(0) => Err(Some(
+                "Port 0 is reserved; please enter a port from 1 to 65535".to_owned(),
+            )),
+            Ok(n) => {
+                u16::try_from(n).map_err(|_| Some("Please enter a port from 1 to 65535".to_owned()))
+            }
+            Err(_) => Err(None),
+        });
+        if self.warn_privileged && port < 1024 {
+            self.write_line(&format!(
+                "Note: port {} is privileged; using it usually needs root or admin rights",
+                port
+            ));
+        }
+        port
+    }
+
+    /// Sets whether [Input::wait_port] warns about ports below 1024, which usually need
+    /// elevated privileges to bind (off by default).
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new()
+    ///     .warn_privileged(true)
+    ///     .chain_source(Box::new(Cursor::new("80\n")));
+    ///
+    /// assert_eq!(input.wait_port("Port: "), 80);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Port: 80
+    /// Note: port 80 is privileged; using it usually needs root or admin rights
+    /// ```
+    pub fn warn_privileged(mut self, w: bool) -> Self {
+        self.warn_privileged = w;
+        self
+    }
+
+    /// Waits until the user enters a number, accepting the usual ways of writing
+    /// scientific notation: `1e3` or `1E3`, `1x10^3`, `1*10^3` or `1×10^3`, and `10^3`.
+    /// Spaces anywhere are ignored (`6.02 x 10^23`), exponents may be signed
+    /// (`1.6x10^-19`), and plain numbers like `42` or `0.5` work too.
+    ///
+    /// Anything else, including `inf`, `NaN` and values too large for an `f64`, is
+    /// rejected and the user is asked again.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "1e3\n1E3\n1x10^3\n6.02 × 10^23\n1e3e3\n2.5\n";
+    /// let input = Input::new().chain_source(Box::new(Cursor::new(lines)));
+    ///
+    /// assert_eq!(input.wait_scientific("Value: "), 1e3);
+    /// assert_eq!(input.wait_scientific("Value: "), 1e3);
+    /// assert_eq!(input.wait_scientific("Value: "), 1e3);
+    /// assert_eq!(input.wait_scientific("Value: "), 6.02e23);
+    /// // The malformed 1e3e3 is rejected, so the next line is used
+    /// assert_eq!(input.wait_scientific("Value: "), 2.5);
+    /// ```
+    pub fn wait_scientific(&self, p: &str) -> f64 {
+        self.wait_parsed(p, parse::scientific)
+    }
+
+    /// Waits until the user enters a duration, written as whole numbers each followed by
+    /// a unit: `ms`, `s`, `m`, `h` or `d`. Units can be combined, as in `1h30m`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    /// use std::time::Duration;
+    ///
+    /// // A bare number is rejected, since it has no unit
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("90\n1m30s\n")));
+    /// let delay = input.wait_duration("Delay: ");
+    ///
+    /// assert_eq!(delay, Duration::from_secs(90));
+    /// ```
+    pub fn wait_duration(&self, p: &str) -> Duration {
+        self.wait_parsed(p, parse::duration)
+    }
+
+    /// Waits until the user enters a range of durations like `5m..1h` (in the format of
+    /// [Input::wait_duration]), returning its start and end.
+    ///
+    /// A range that ends before it starts is rejected with a message saying so.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    /// use std::time::Duration;
+    ///
+    /// // Backwards, then the right way around
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("1h..5m\n5m..1h\n")));
+    /// let (start, end) = input.wait_duration_range("Run between: ");
+    ///
+    /// assert_eq!((start, end), (Duration::from_secs(300), Duration::from_secs(3600)));
+    /// ```
+    pub fn wait_duration_range(&self, p: &str) -> (Duration, Duration) {
+        self.wait_parsed_msg(p, |s| {
+            let (start, end) = s.split_once("..").ok_or(None)?;
+            let start = parse::duration(start).ok_or(None)?;
+            let end = parse::duration(end).ok_or(None)?;
+            if start > end {
+                return Err(Some("The range can't end before it starts".to_owned()));
+            }
+            Ok((start, end))
+        })
+    }
+
+    /// Waits until the user enters a number with a unit, like `2kg` or `2 kg`, returning it
+    /// converted to the `base` unit.
+    ///
+    /// `units` pairs each accepted unit name with how many `base` units it's worth. The
+    /// `base` unit itself is always accepted, as is a number with no unit at all. Unit
+    /// names are matched exactly, since case often matters (`mm` and `Mm`); any other
+    /// unit is rejected with a message listing the accepted ones.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let units = [("kg", 1000.0), ("mg", 0.001)];
+    /// // "lb" isn't one of the units, so the user is asked again
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("3lb\n2kg\n")));
+    /// let grams = input.wait_quantity("Weight: ", &units, "g");
+    ///
+    /// assert_eq!(grams, 2000.0);
+    /// ```
+    pub fn wait_quantity(&self, p: &str, units: &[(&str, f64)], base: &str) -> f64 {
+        self.wait_parsed_msg(p, |s| {
+            // The longest leading number, so a unit like "eV" isn't taken as an exponent
+            let (value, unit) = (1..=s.len())
+                .rev()
+                .filter(|&i| s.is_char_boundary(i))
+                .find_map(|i| Some((s[..i].parse::<f64>().ok()?, &s[i..])))
+                .ok_or(None)?;
+
+            let unit = unit.trim();
+            if unit.is_empty() || unit == base {
+                return Ok(value);
+            }
+            match units.iter().find(|(name, _)| *name == unit) {
+                Some((_, scale)) => Ok(value * scale),
+                None => {
+                    let names: Vec<&str> = std::iter::once(base)
+                        .chain(units.iter().map(|(name, _)| *name))
+                        .collect();
+                    Err(Some(format!(
+                        "Unknown unit '{}'; expected one of {}",
+                        unit,
+                        names.join(", ")
+                    )))
+                }
+            }
+        })
+    }
+
+    /// Waits until the user enters a number from `min` to `max` (inclusive) that's a whole
+    /// number of `step`s above `min`, such as 0 to 1 in steps of 0.1.
+    ///
+    /// Since decimal steps can't be represented exactly, a value counts as aligned when
+    /// it's within a millionth of a step (`step * 1e-6`) of a multiple of it. The number
+    /// is returned as entered, not rounded to the step.
+    ///
+    /// Panics if `step` isn't positive.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// // Out of range, then between steps, then just right
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("1.5\n0.35\n0.3\n")));
+    /// let opacity = input.wait_stepped(0.0, 1.0, 0.1, "Opacity: ");
+    ///
+    /// assert_eq!(opacity, 0.3);
+    /// ```
+    pub fn wait_stepped(&self, min: f64, max: f64, step: f64, p: &str) -> f64 {
+        assert!(step > 0.0, "step must be positive");
+
+        self.wait_parsed_msg(p, |s| {
+            let value: f64 = self.parse_str(s).map_err(|_| None)?;
+            if !(min..=max).contains(&value) {
+                return Err(Some(format!(
+                    "Please enter a number within the bounds {:?}",
+                    min..=max
+                )));
+            }
+
+            let steps = (value - min) / step;
+            if (steps - steps.round()).abs() > 1e-6 {
+                return Err(Some(format!(
+                    "Please enter a value in steps of {} from {}",
+                    step, min
+                )));
+            }
+            Ok(value)
+        })
+    }
+
+    /// Waits until the user responds with something that can be parsed to `T` and passes
+    /// `check`, printing the message `check` rejects it with otherwise.
+    ///
+    /// Input that doesn't parse shows `err_msg` as usual. Input that parses but fails
+    /// `check` shows only the check's message, never `err_msg`. The `quit` trigger is
+    /// matched before either, so it's never parsed or checked.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let age: u32 = Input::new()
+    ///     .err_msg("That wasn't a number")
+    ///     .with_reader(&b"old\n16\n21\n"[..])
+    ///     .wait_check("Enter your age: ", |age| {
+    ///         if *age >= 18 {
+    ///             Ok(())
+    ///         } else {
+    ///             Err(format!("You must be 18 or older, not {}", age))
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(age, 21);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Enter your age: old
+    /// That wasn't a number
+    /// Enter your age: 16
+    /// You must be 18 or older, not 16
+    /// Enter your age: 21
+    /// ```
+    pub fn wait_check<T, F>(&self, p: &str, check: F) -> T
+    where
+        T: std::str::FromStr,
+        F: Fn(&T) -> Result<(), String>,
+    {
+        self.wait_parsed_msg(p, |s| {
+            let value = self.parse_valid(s)?;
+            check(&value).map_err(Some)?;
+            Ok(value)
+        })
+    }
+
+    /// Like [Input::wait_check], but with the checks given as an ordered list of rules,
+    /// each a message and a predicate the value has to pass. The rules are tried in
+    /// order, and only the message of the first one failing is shown before asking
+    /// again.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("7\n12\n")));
+    /// let rules: [(&str, Box<dyn Fn(&u32) -> bool>); 2] = [
+    ///     ("Please enter at least 4 players", Box::new(|n| *n >= 4)),
+    ///     ("Teams need an even number of players", Box::new(|n| n % 2 == 0)),
+    /// ];
+    ///
+    /// assert_eq!(input.wait_rules("Players: ", &rules), 12);
+    /// ```
+    /// What the user sees, since 7 passes the first rule but not the second:
+    /// ```plaintext
+    /// Players: 7
+    /// Teams need an even number of players
+    /// Players: 12
+    /// ```
+    pub fn wait_rules<T>(&self, p: &str, rules: &[(&str, Box<RuleFn<T>>)]) -> T
+    where
+        T: std::str::FromStr,
+    {
+        self.wait_check(p, |value| {
+            match rules.iter().find(|(_, rule)| !rule(value)) {
+                Some((msg, _)) => Err(msg.to_string()),
+                None => Ok(()),
+            }
+        })
+    }
+
+    /// Asks the user to edit `current`, for update screens: it's shown in brackets in the
+    /// prompt, like a [Input::default_value], just pressing Enter keeps it, and anything
+    /// else entered replaces it, asking again if it can't be parsed.
+    ///
+    /// There's no way to place `current` on the input line itself for the user to edit
+    /// in place, so it's always shown as a default, even with
+    /// [show_default(false)](Input::show_default).
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("\nlots\n8\n")));
+    ///
+    /// assert_eq!(input.wait_edit(4, "Threads: "), 4);
+    /// assert_eq!(input.wait_edit(4, "Threads: "), 8);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Threads [4]:
+    /// Threads [4]: lots
+    /// Threads [4]: 8
+    /// ```
+    pub fn wait_edit<T>(&self, current: T, p: &str) -> T
+    where
+        T: std::str::FromStr + fmt::Display,
+    {
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.hide_default = false;
+        ic.sticky = false;
+        ic.sticky_value.take();
+        ic.memory = None;
+        let ic = ic.default_value(current);
+
+        let value = ic.wait_parsed_msg(p, |s| ic.parse_valid(s));
+        self.print_success();
+        self.last_input.replace(ic.last_input.take());
+        self.accepted();
+        value
+    }
+
+    /// Asks for a value with `p` and then again with `confirm_p`, returning it once both
+    /// answers match. When they don't, "The values didn't match" is printed and the user
+    /// starts over from `p`, as for setting a password (see [Input::secret]).
+    ///
+    /// With `soft_quit`, the quit trigger at either prompt also starts the pair over,
+    /// rather than just asking that half again; otherwise it ends the program as usual.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "hunter2\nhunter3\nhunter2\nhunter2\n";
+    /// let input = Input::new().chain_source(Box::new(Cursor::new(lines)));
+    /// let password: String = input.wait_confirmed("New password: ", "Repeat it: ");
+    ///
+    /// assert_eq!(password, "hunter2");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// New password: hunter2
+    /// Repeat it: hunter3
+    /// The values didn't match; please try again
+    /// New password: hunter2
+    /// Repeat it: hunter2
+    /// ```
+    pub fn wait_confirmed<T>(&self, p: &str, confirm_p: &str) -> T
+    where
+        T: std::str::FromStr + PartialEq,
+    {
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.hold_quit.set(self.soft_quit);
+        // Only the value confirmed is remembered, by `accepted` below
+        ic.sticky = false;
+        ic.memory = None;
+        let parse = |s: &str| ic.parse_valid(ic.strip(s));
+
+        loop {
+            let Ok(first) = ic.wait_loop(p, parse, false) else {
+                continue;
+            };
+            let Ok(second) = ic.wait_loop(confirm_p, parse, false) else {
+                continue;
+            };
+            if first == second {
+                self.print_success();
+                self.last_input.replace(ic.last_input.take());
+                self.accepted();
+                return first;
+            }
+            self.print_error("The values didn't match; please try again");
+        }
+    }
+
+    /// Waits until the user enters something that can be parsed to `T`, then shows it
+    /// back as `fmt` formats it and asks (like [Input::choose]) whether that's what they
+    /// meant, asking for the value again on "no". This catches input that parsed, but
+    /// not as intended, such as a date in the wrong order.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "1e3\nn\n1.3\ny\n";
+    /// let input = Input::new().chain_source(Box::new(Cursor::new(lines)));
+    /// let price: f64 = input.wait_confirm_format("Price: ", |p| format!("${:.2}", p));
+    ///
+    /// assert_eq!(price, 1.3);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Price: 1e3
+    /// Did you mean $1000.00? [y/n] n
+    /// Price: 1.3
+    /// Did you mean $1.30? [y/n] y
+    /// ```
+    pub fn wait_confirm_format<T, F>(&self, p: &str, fmt: F) -> T
+    where
+        T: std::str::FromStr,
+        F: Fn(&T) -> String,
+    {
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.sticky = false;
+        ic.memory = None;
+
+        loop {
+            let value = ic.wait_parsed_msg(p, |s| ic.parse_valid(s));
+            let entered = ic.last_input.take();
+            if ic.ask_yes_no(&format!("Did you mean {}?", fmt(&value))).0 {
+                self.print_success();
+                self.last_input.replace(entered);
+                self.accepted();
+                return value;
+            }
+        }
+    }
+
+    /// Like [Input::wait_check], but shows a spinner while `check` runs, for checks that
+    /// do slow work like a network lookup.
+    ///
+    /// The spinner is only drawn with the `tty` feature, and only when output goes to
+    /// stdout and stdout is a terminal; otherwise `check` simply runs with nothing
+    /// animated.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let taken = ["alice", "bob"];
+    /// let name: String = Input::new()
+    ///     .chain_source(Box::new(Cursor::new("bob\ncarol\n")))
+    ///     .wait_checked_with_spinner("Username: ", |name: &String| {
+    ///         // Imagine asking a server here
+    ///         if taken.contains(&name.as_str()) {
+    ///             Err(format!("{} is taken", name))
+    ///         } else {
+    ///             Ok(())
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(name, "carol");
+    /// ```
+    pub fn wait_checked_with_spinner<T, F>(&self, p: &str, check: F) -> T
+    where
+        T: std::str::FromStr,
+        F: Fn(&T) -> Result<(), String>,
+    {
+        self.wait_check(p, |value| {
+            #[cfg(feature = "tty")]
+            if self.writer.is_none() {
+                return term::spin(self.to_stderr, || check(value));
+            }
+            check(value)
+        })
+    }
+
+    /// Prompts with `p` `n` times, returning the `n` values read, in order.
+    ///
+    /// Each value is read as by `wait`, so `err_msg` and the quit trigger work as usual.
+    /// In `p`, the placeholder `{index}` is replaced with the number of the value being
+    /// read, starting at 1.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("3\nfour\n4\n5\n")));
+    /// let sides: Vec<u32> = input.collect_n("Side {index}: ", 3);
+    ///
+    /// assert_eq!(sides, [3, 4, 5]);
+    /// ```
+    /// The user in the above case would see the following:
+    /// ```plaintext
+    /// Side 1: 3
+    /// Side 2: four
+    /// Side 2: 4
+    /// Side 3: 5
+    /// ```
+    pub fn collect_n<T>(&self, p: &str, n: usize) -> Vec<T>
+    where
+        T: std::str::FromStr,
+    {
+        let items = (1..=n)
+            .map(|i| {
+                self.index.set(Some(i));
+                self.wait_parsed_msg(p, |s| self.parse_valid(s))
+            })
+            .collect();
+        self.index.set(None);
+        items
+    }
+
+    /// Prompts for values one after another until the user enters `stop`, returning
+    /// every value read, in order.
+    ///
+    /// Each value is read as by `wait`, so an entry that can't be used shows `err_msg`
+    /// and is left out. `stop` is compared against the trimmed input, and is separate
+    /// from the quit trigger, which still ends the program; with `soft_quit` it ends
+    /// the list instead, as do the end of input and giving up under
+    /// [Input::max_attempts] or [Input::abort_on_repeat].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .err_msg("Not a weight")
+    ///     .chain_source(Box::new(Cursor::new("2.5\nheavy\n4\ndone\n7\n")));
+    /// let weights: Vec<f64> = input.prompt("Weight (or done): ").wait_collect("done");
+    ///
+    /// assert_eq!(weights, [2.5, 4.0]);
+    /// ```
+    pub fn wait_collect<T>(&self, stop: &str) -> Vec<T>
+    where
+        T: std::str::FromStr,
+    {
+        let mut items = Vec::new();
+        loop {
+            let item = self.try_wait_raw(&self.user_prompt, |s| match s.trim() {
+                s if s == stop => Ok(None),
+                s => self.parse_valid(s).map(Some),
+            });
+            match item {
+                Ok(Some(item)) => items.push(item),
+                Ok(None) | Err(_) => return items,
+            }
+        }
+    }
+
+    /// Waits until the user enters something that parses to `T` and that the async
+    /// `check` accepts, showing the message `check` rejects it with otherwise.
+    ///
+    /// `check` receives the parsed value and hands back the value to return, so it can
+    /// normalize it along the way. Reading input still blocks; only `check` is awaited,
+    /// so it can make network calls on whatever runtime drives this future.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake};
+    /// # struct Unpark(std::thread::Thread);
+    /// # impl Wake for Unpark {
+    /// #     fn wake(self: Arc<Self>) {
+    /// #         self.0.unpark();
+    /// #     }
+    /// # }
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let waker = Arc::new(Unpark(std::thread::current())).into();
+    /// #     let mut cx = Context::from_waker(&waker);
+    /// #     let mut fut = pin!(fut);
+    /// #     loop {
+    /// #         match fut.as_mut().poll(&mut cx) {
+    /// #             Poll::Ready(out) => return out,
+    /// #             Poll::Pending => std::thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// use std::io::Cursor;
+    ///
+    /// async fn available(name: String) -> Result<String, String> {
+    ///     // Imagine asking a server here
+    ///     if name == "admin" {
+    ///         Err(format!("{} is taken", name))
+    ///     } else {
+    ///         Ok(name)
+    ///     }
+    /// }
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("admin\nada\n")));
+    /// let name: String = block_on(input.wait_validated_async("Username: ", available));
+    ///
+    /// assert_eq!(name, "ada");
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn wait_validated_async<T, F, Fut>(&self, p: &str, check: F) -> T
+    where
+        T: std::str::FromStr,
+        F: Fn(T) -> Fut,
+        Fut: std::future::Future<Output = Result<T, String>>,
+    {
+        let mut attempts = 0;
+
+        loop {
+            self.attempt.set(attempts + 1);
+            let response = self.get_raw_data(p, |s| self.parse_valid(s.trim()));
+            self.check_error(&response);
+            attempts += 1;
+
+            let Ok(value) = response else {
+                continue;
+            };
+            match check(value).await {
+                Ok(value) => {
+                    self.attempt.set(0);
+                    if let Some(answer) = &self.last_input.borrow().1 {
+                        self.echo_answer(p, answer);
+                    }
+                    self.print_success();
+                    self.log_interaction(p, attempts);
+                    self.accepted();
+                    return value;
+                }
+                Err(msg) => self.print_error(&msg),
+            }
+        }
+    }
+
+    /// Like [Input::wait], but waits for the user without blocking, for use in async code.
+    ///
+    /// The quit trigger gives `Err(Quit)` instead of ending the program, whether or not
+    /// `soft_quit` is set, since exiting in the middle of a runtime skips its cleanup.
+    /// Otherwise, parsing, `err_msg` and asking again work just as for `wait`.
+    ///
+    /// Only stdin is waited for; with a `chain_source`, `answers_file` or `with_reader`,
+    /// lines are read straight away. A line typed after the future was dropped is kept
+    /// for the next read.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, Quit};
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake};
+    /// # struct Unpark(std::thread::Thread);
+    /// # impl Wake for Unpark {
+    /// #     fn wake(self: Arc<Self>) {
+    /// #         self.0.unpark();
+    /// #     }
+    /// # }
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let waker = Arc::new(Unpark(std::thread::current())).into();
+    /// #     let mut cx = Context::from_waker(&waker);
+    /// #     let mut fut = pin!(fut);
+    /// #     loop {
+    /// #         match fut.as_mut().poll(&mut cx) {
+    /// #             Poll::Ready(out) => return out,
+    /// #             Poll::Pending => std::thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new()
+    ///     .quit("quit")
+    ///     .chain_source(Box::new(Cursor::new("lots\n3\nquit\n")));
+    /// input.prompt("Retries: ");
+    ///
+    /// assert_eq!(block_on(input.wait_async::<u8>()), Ok(3));
+    /// assert_eq!(block_on(input.wait_async::<u8>()), Err(Quit));
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn wait_async<T>(&self) -> Result<T, Quit>
+    where
+        T: std::str::FromStr,
+    {
+        let p = &self.user_prompt;
+        let mut attempts = 0;
+
+        loop {
+            self.attempt.set(attempts + 1);
+            self.prompt_ahead(p).await;
+            let held = self.hold_quit.replace(true);
+            let response = self.get_raw_data(p, |s| self.parse_valid(self.strip(s)));
+            self.hold_quit.set(held);
+            attempts += 1;
+
+            if let Ok(value) = response {
+                self.attempt.set(0);
+                if let Some(answer) = &self.last_input.borrow().1 {
+                    self.echo_answer(p, answer);
+                }
+                self.print_success();
+                self.log_interaction(p, attempts);
+                self.accepted();
+                return Ok(value);
+            }
+            if self.quit_flag.get() {
+                self.attempt.set(0);
+                self.log_interaction(p, attempts);
+                return Err(Quit);
+            }
+            self.check_error(&response);
+        }
+    }
+
+    /// Like [Input::try_read], but waits for the user without blocking, for use in async
+    /// code; see [Input::wait_async]. The quit trigger always gives [InputError::Quit].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::sync::Arc;
+    /// # use std::task::{Context, Poll, Wake};
+    /// # struct Unpark(std::thread::Thread);
+    /// # impl Wake for Unpark {
+    /// #     fn wake(self: Arc<Self>) {
+    /// #         self.0.unpark();
+    /// #     }
+    /// # }
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let waker = Arc::new(Unpark(std::thread::current())).into();
+    /// #     let mut cx = Context::from_waker(&waker);
+    /// #     let mut fut = pin!(fut);
+    /// #     loop {
+    /// #         match fut.as_mut().poll(&mut cx) {
+    /// #             Poll::Ready(out) => return out,
+    /// #             Poll::Pending => std::thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// let mut input = Input::new().with_reader(&b"12\ntwelve\n"[..]);
+    /// input.prompt("Number: ");
+    ///
+    /// assert!(matches!(block_on(input.read_async::<u32>()), Ok(12)));
+    /// assert!(matches!(
+    ///     block_on(input.read_async::<u32>()),
+    ///     Err(InputError::Parse { input }) if input == "twelve"
+    /// ));
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn read_async<T>(&self) -> Result<T, InputError>
+    where
+        T: std::str::FromStr,
+    {
+        self.prompt_ahead(&self.user_prompt).await;
+        let held = self.hold_quit.replace(true);
+        let response = self
+            .fallibly(|| self.get_raw_data(&self.user_prompt, |s| self.parse_valid(self.strip(s))));
+        self.hold_quit.set(held);
+        self.log_interaction(&self.user_prompt, 1);
+
+        match response {
+            Ok(value) => {
+                self.accepted();
+                Ok(value)
+            }
+            Err(_) => Err(self.failed_read()),
+        }
+    }
+
+    /// Displays `p` once, then reads one entry per line until the user enters a blank
+    /// line (or input ends), returning every entry that parsed to `T`.
+    ///
+    /// An entry that doesn't parse is left out of the list and `err_msg` is printed,
+    /// so the user can simply retype it on the next line; with [Input::list_skip_invalid]
+    /// it's left out silently instead. With `soft_quit`, the quit trigger ends the list
+    /// early, keeping the entries read so far.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let numbers: Vec<i32> = Input::new()
+    ///     .err_msg("Not a number; retype it")
+    ///     .with_reader(&b"1\ntwo\n2\n3\n\n"[..])
+    ///     .wait_list("Enter numbers, one per line:\n");
+    ///
+    /// assert_eq!(numbers, [1, 2, 3]);
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Enter numbers, one per line:
+    /// 1
+    /// two
+    /// Not a number; retype it
+    /// 2
+    /// 3
+    ///
+    /// ```
+    pub fn wait_list<T>(&self, p: &str) -> Vec<T>
+    where
+        T: std::str::FromStr,
+    {
+        let mut items = Vec::new();
+        let mut prompt = Some(p);
+
+        loop {
+            let mut buffer = String::new();
+            if self.read_input(prompt.take(), &mut buffer) || buffer.trim().is_empty() {
+                return items;
+            }
+
+            let line = buffer.trim();
+            let response = self.parse_valid(line);
+            if !self.list_skip_invalid {
+                self.check_error(&response);
+            }
+            if let Ok(item) = response {
+                self.note_input(line, Some(line));
+                self.accepted();
+                items.push(item);
+            }
+        }
+    }
+
+    /// Waits until the user enters a valid position in a collection of length `len`,
+    /// returning it as a zero-based index.
+    ///
+    /// The user counts from `base`, usually 1 (or 0 for zero-based positions); out of
+    /// range entries are re-prompted with the valid bounds, just like `wait_opts`.
+    ///
+    /// Panics if `len` is 0, since no index could ever be valid.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("0\n4\n3\n0\n")));
+    /// let files = ["a.txt", "b.txt", "c.txt"];
+    ///
+    /// // For 1-based positions, 0 and 4 are out of range, so 3 is picked
+    /// let index = input.wait_index(files.len(), 1, "Which file? ");
+    /// assert_eq!(files[index], "c.txt");
+    ///
+    /// // 0 is valid when counting from 0
+    /// assert_eq!(input.wait_index(files.len(), 0, "Which file? "), 0);
+    /// ```
+    pub fn wait_index(&self, len: usize, base: usize, p: &str) -> usize {
+        assert!(len > 0, "wait_index needs a non-empty collection");
+        let bounds = base..=base + len - 1;
+
+        self.wait_parsed_msg(p, |s| {
+            let n: usize = self.parse_str(s).map_err(|_| None)?;
+            if bounds.contains(&n) {
+                Ok(n - base)
+            } else {
+                Err(Some(format!(
+                    "Please enter a number within the bounds {:?}",
+                    bounds
+                )))
+            }
+        })
+    }
+
+    /// Waits until the user enters a line of whitespace-separated values that all parse
+    /// to `T`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("1 2 x\n1 2 3\n")));
+    /// let scores: Vec<u32> = input.wait_vec("Scores: ");
+    ///
+    /// assert_eq!(scores, [1, 2, 3]);
+    /// ```
+    pub fn wait_vec<T>(&self, p: &str) -> Vec<T>
+    where
+        T: std::str::FromStr,
+    {
+        self.wait_parsed(p, |s| {
+            s.split_whitespace().map(|word| word.parse().ok()).collect()
+        })
+    }
+
+    /// Like [Input::wait_vec], but requires exactly `n` values, telling the user how
+    /// many they entered otherwise.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// // Too few, then too many, then just right
+    /// let lines = "4 2\n4 2 0 1\n4 2 0\n";
+    /// let input = Input::new().chain_source(Box::new(Cursor::new(lines)));
+    /// let xyz: Vec<f64> = input.wait_vec_n("Coordinates (x y z): ", 3);
+    ///
+    /// assert_eq!(xyz, [4.0, 2.0, 0.0]);
+    /// ```
+    pub fn wait_vec_n<T>(&self, p: &str, n: usize) -> Vec<T>
+    where
+        T: std::str::FromStr,
+    {
+        self.wait_parsed_msg(p, |s| {
+            let count = s.split_whitespace().count();
+            if count != n {
+                return Err(Some(format!("Expected {} values, got {}", n, count)));
+            }
+            s.split_whitespace()
+                .map(|word| word.parse().map_err(|_| None))
+                .collect()
+        })
+    }
+
+    /// Waits until the user responds with something that parses to `T` and isn't already
+    /// in `existing`, saying it was already entered otherwise.
+    ///
+    /// `existing` is only read, so collecting distinct values is a matter of inserting
+    /// each returned value before the next call.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::collections::HashSet;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("7\n7\n9\n")));
+    /// let mut seen = HashSet::new();
+    ///
+    /// seen.insert(input.wait_unique::<u32>("Ticket: ", &seen));
+    /// // The second 7 is rejected, so 9 is read instead
+    /// seen.insert(input.wait_unique::<u32>("Ticket: ", &seen));
+    ///
+    /// assert_eq!(seen, HashSet::from([7, 9]));
+    /// ```
+    pub fn wait_unique<T>(&self, p: &str, existing: &HashSet<T>) -> T
+    where
+        T: std::str::FromStr + Eq + Hash,
+    {
+        self.wait_parsed_msg(p, |s| {
+            let value = self.parse_valid(s)?;
+            if existing.contains(&value) {
+                return Err(Some(format!("'{}' was already entered", s)));
+            }
+            Ok(value)
+        })
+    }
+
+    /// Waits until the user enters one of the `allowed` words (ignoring case),
+    /// returning it as spelled in `allowed`.
+    ///
+    /// Unlike `wait_opts`, no menu is shown. If the input is close to one of the
+    /// allowed words, the user is asked whether they meant that instead of being
+    /// shown `err_msg`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let cmd = Input::new()
+    ///     .err_msg("Unknown command")
+    ///     .with_reader(&b"stat\nSTOP\n"[..])
+    ///     .wait_allowed(&["start", "stop", "status"], "Command: ");
+    ///
+    /// assert_eq!(cmd, "stop");
+    /// ```
+    /// What the user would see after a typo:
+    /// ```plaintext
+    /// Command: stat
+    /// Did you mean 'start'?
+    /// Command:
+    /// ```
+    pub fn wait_allowed(&self, allowed: &[&str], p: &str) -> String {
+        self.wait_raw(p, |s| {
+            let s = s.trim();
+            match allowed.iter().find(|a| a.eq_ignore_ascii_case(s)) {
+                Some(a) => Ok(a.to_string()),
+                None => Err(closest(s, allowed, self.fuzzy_distance)
+                    .map(|a| format!("Did you mean '{}'?", a))),
+            }
+        })
+    }
+
+    /// Like [Input::wait_allowed], but when the input is close to one of the `allowed`
+    /// words, asks whether that's what the user meant and accepts it on "yes". On "no",
+    /// the user is shown `err_msg` and asked again.
+    ///
+    /// How close counts as a typo can be set with [Input::fuzzy_distance].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("stat\ny\n")));
+    /// let cmd = input.wait_allowed_fuzzy(&["start", "stop", "status"], "Command: ");
+    ///
+    /// assert_eq!(cmd, "start");
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Command: stat
+    /// Did you mean 'start'? [y/n] y
+    /// ```
+    pub fn wait_allowed_fuzzy(&self, allowed: &[&str], p: &str) -> String {
+        self.wait_raw(p, |s| {
+            let s = s.trim();
+            if let Some(a) = allowed.iter().find(|a| a.eq_ignore_ascii_case(s)) {
+                return Ok(a.to_string());
+            }
+            match closest(s, allowed, self.fuzzy_distance) {
+                Some(a) if self.ask_yes_no(&format!("Did you mean '{}'?", a)).0 => {
+                    Ok(a.to_string())
+                }
+                _ => Err(None),
+            }
+        })
+    }
+
+    /// Waits until the user enters one or more whitespace-separated paths.
+    ///
+    /// Paths containing spaces can be wrapped in single or double quotes. With the
+    /// `glob` feature enabled, unquoted words containing `*`, `?` or `[` are expanded
+    /// to the files they match, and a pattern matching nothing re-prompts the user
+    /// (see [Input::allow_empty_glob]).
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let paths = Input::new()
+    ///     .with_reader(&b"notes.txt \"my report.pdf\"\n"[..])
+    ///     .wait_paths("Files: ");
+    ///
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[1].to_str(), Some("my report.pdf"));
+    /// ```
+    ///
+    /// Expanding a glob:
+    /// ```
+    /// # use promptis::Input;
+    /// # #[cfg(feature = "glob")]
+    /// # {
+    /// use std::fs;
+    /// use std::io::Cursor;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("promptis-wait-paths-{}", std::process::id()));
+    /// fs::create_dir_all(&dir).unwrap();
+    /// for name in ["a.rs", "b.rs", "notes.txt"] {
+    ///     fs::write(dir.join(name), "").unwrap();
+    /// }
+    ///
+    /// // Nothing matches *.md, so the user is asked again
+    /// let lines = format!("{0}/*.md\n{0}/*.rs\n", dir.display());
+    /// let mut paths = Input::new()
+    ///     .with_reader(Cursor::new(lines))
+    ///     .wait_paths("Files: ");
+    /// paths.sort();
+    ///
+    /// assert_eq!(paths, [dir.join("a.rs"), dir.join("b.rs")]);
+    /// # fs::remove_dir_all(&dir).unwrap();
+    /// # }
+    /// ```
+    pub fn wait_paths(&self, p: &str) -> Vec<PathBuf> {
+        self.wait_parsed_msg(p, |s| {
+            let words = parse::split_words(s).ok_or(None)?;
+            if words.is_empty() {
+                return Err(None);
+            }
+
+            let mut paths = Vec::new();
+            for (word, quoted) in words {
+                paths.extend(self.expand_path(word, quoted)?);
+            }
+
+            Ok(paths)
+        })
+    }
+
+    /// Expands an unquoted glob pattern entered for `wait_paths` into the files it matches
+    #[cfg(feature = "glob")]
+    fn expand_path(&self, word: String, quoted: bool) -> Result<Vec<PathBuf>, Option<String>> {
+        if quoted || !glob::is_pattern(&word) {
+            return Ok(vec![word.into()]);
+        }
+
+        let found = glob::expand(&word);
+        if !found.is_empty() {
+            Ok(found)
+        } else if self.glob_nomatch_literal {
+            Ok(vec![word.into()])
+        } else {
+            Err(Some(format!("No files match '{}'", word)))
+        }
+    }
+
+    /// Without the `glob` feature, every word entered for `wait_paths` is a literal path
+    #[cfg(not(feature = "glob"))]
+    fn expand_path(&self, word: String, _quoted: bool) -> Result<Vec<PathBuf>, Option<String>> {
+        Ok(vec![word.into()])
+    }
+
+    /// Waits until the user enters an amount of money, such as `$1,234.56` or `-$5`.
+    ///
+    /// The currency symbol (see [Input::currency]) may come before or after the number,
+    /// a `-` or `+` sign may lead or trail, and `,` thousands separators are allowed as
+    /// long as they group digits by threes.
+    ///
+    /// The amount is converted to the nearest `f64`, so most amounts with cents can't be
+    /// represented exactly; use [Input::wait_cents] when exactness matters.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// // "$12,34" doesn't group its digits by threes, so the user is asked again
+    /// let price = Input::new()
+    ///     .with_reader(&b"$12,34\n$1,234.56\n"[..])
+    ///     .wait_money("Price: ");
+    ///
+    /// assert_eq!(price, 1234.56);
+    /// ```
+    pub fn wait_money(&self, p: &str) -> f64 {
+        self.wait_parsed(p, |s| Some(self.parse_amount(s)?.to_f64()))
+    }
+
+    /// Like [Input::wait_money], but returns the exact amount in cents.
+    ///
+    /// No rounding is done: amounts with fractions of a cent (like `1.005`) are rejected,
+    /// as are amounts too large for an `i64`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// // A fraction of a cent can't be represented, so the user is asked again
+    /// let change = Input::new()
+    ///     .with_reader(&b"1.005\n-$5\n"[..])
+    ///     .wait_cents("Change: ");
+    ///
+    /// assert_eq!(change, -500);
+    /// ```
+    pub fn wait_cents(&self, p: &str) -> i64 {
+        self.wait_parsed(p, |s| self.parse_amount(s)?.to_cents())
+    }
+
+    /// Waits until the user enters a valid semantic version, like `1.4.2` or `2.0.0-rc.1`.
+    ///
+    /// Invalid input is re-prompted with a message saying what's wrong with it.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, Version};
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("1.2\n1.2.0\n")));
+    /// let v: Version = input.wait_semver("Release version: ");
+    ///
+    /// assert_eq!(v.to_string(), "1.2.0");
+    /// ```
+    #[cfg(feature = "semver")]
+    pub fn wait_semver(&self, p: &str) -> Version {
+        self.wait_parsed_msg(p, |s| {
+            s.parse()
+                .map_err(|e| Some(format!("'{}' isn't a valid version: {}", s, e)))
+        })
+    }
+
+    /// Displays `p` and reads a line without echoing it, returning the SHA-256 digest of
+    /// the line (without its line ending) as lowercase hex. Empty input is asked again.
+    ///
+    /// Like [Input::try_wait], this gives up with [InputError::Eof] once input ends,
+    /// [InputError::Io] if it can't be read, and [InputError::Quit] for the quit trigger
+    /// with [Input::soft_quit].
+    ///
+    /// The plaintext never leaves this method: it isn't kept as the last input, logged,
+    /// remembered or handed to `on_accept`, and the line it's hashed from is zeroed
+    /// afterwards. Copies made while reading it, such as in stdin's buffer, aren't
+    /// wiped, so this keeps the secret out of your program's hands rather than out of
+    /// memory. When stdin isn't a terminal the line is read as usual, with nothing shown
+    /// in its place.
+    ///
+    /// Requires the `hashing` feature.
+    ///
+    /// # Example
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// let input = Input::new().with_reader(&b"\nhunter2\n"[..]);
+    /// let digest = input.wait_hashed("Choose a password: ");
+    ///
+    /// assert_eq!(
+    ///     digest.ok().as_deref(),
+    ///     Some("f52fbd32b2b3b86ff88ef6c490628285f482af15ddcb29541f94bcf526a3f6c7")
+    /// );
+    /// assert!(matches!(input.wait_hashed("Again: "), Err(InputError::Eof)));
+    /// ```
+    #[cfg(feature = "hashing")]
+    pub fn wait_hashed(&self, p: &str) -> Result<String, InputError> {
+        let mut buffer = String::new();
+        let secret = self.hidden.replace(true);
+        let read = self.fallibly(|| loop {
+            buffer.clear();
+            let quit = self.read_input(Some(p), &mut buffer);
+            if let Some(e) = self.read_failure() {
+                return Err(e);
+            }
+            if quit && self.soft_quit {
+                return Err(InputError::Quit);
+            }
+            if !quit && !buffer.trim().is_empty() {
+                return Ok(());
+            }
+        });
+        self.hidden.set(secret);
+
+        let line = buffer.trim_end_matches(['\n', '\r']);
+        let digest = read.map(|_| hash::sha256_hex(line.as_bytes()));
+        hash::zeroize(&mut std::mem::take(&mut buffer).into_bytes());
+        digest
+    }
+
+    /// Parses an amount of money using this Input's currency symbol
+    fn parse_amount(&self, s: &str) -> Option<parse::Amount> {
+        parse::Amount::parse(s, self.currency.as_deref().unwrap_or("$"))
+    }
+
+    /// Parses `s` like `parse_str`, keeping the value only if it passes the `validate`
+    /// predicate, is written as `require_canonical` asks and falls within the `min` and
+    /// `max` bounds (when there are ones for `T`), or else saying why not
+    fn parse_valid<T>(&self, s: &str) -> Result<T, Option<String>>
+    where
+        T: std::str::FromStr,
+    {
+        let value = self.parse_str(s).map_err(|_| None)?;
+        let text = self.parsed_text(s);
+        let checks = [&self.validator, &self.canonical, &self.bounds_check];
+        for check in checks.into_iter().flatten() {
+            check.run::<T>(self, text)?;
+        }
+        Ok(value)
+    }
+
+    /// The bounds set for values of type `T` so far
+    fn bounds_of<T>(&self) -> parse::Bounds<T>
+    where
+        T: PartialOrd + fmt::Display + Clone + 'static,
+    {
+        self.bounds
+            .as_ref()
+            .and_then(|b| b.0.downcast_ref::<parse::Bounds<T>>())
+            .cloned()
+            .unwrap_or_else(parse::Bounds::new)
+    }
+
+    /// Parses trimmed input with [std::str::FromStr], honoring `accept_leading`
+    fn parse_str<T>(&self, s: &str) -> Result<T, T::Err>
+    where
+        T: std::str::FromStr,
+    {
+        self.parsed_text(s).parse()
+    }
+
+    /// The part of `s` that's parsed: all of it, or with `accept_leading` its first word
+    fn parsed_text<'a>(&self, s: &'a str) -> &'a str {
+        if self.accept_leading {
+            s.split_whitespace().next().unwrap_or("")
+        } else {
+            s
+        }
+    }
+
+    /// Displays `p` until the user responds with something `parse` accepts
+    fn wait_parsed<T, F>(&self, p: &str, parse: F) -> T
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        self.wait_raw(p, |s| parse(s.trim()).ok_or(None))
+    }
+
+    /// Like `wait_parsed`, but `parse` can reject the input with its own message,
+    /// which is shown instead of `err_msg`
+    fn wait_parsed_msg<T, F>(&self, p: &str, parse: F) -> T
+    where
+        F: Fn(&str) -> Result<T, Option<String>>,
+    {
+        self.wait_raw(p, |s| parse(s.trim()))
+    }
+
+    /// Like `wait_parsed`, but `parse` sees the line untrimmed (minus its line ending)
+    /// and can reject it with its own message, which is shown instead of `err_msg`
+    fn wait_raw<T, F>(&self, p: &str, parse: F) -> T
+    where
+        F: Fn(&str) -> Result<T, Option<String>>,
+    {
+        match self.unheld(|| self.wait_loop(p, parse, false)) {
+            Ok(value) => value,
+            Err(_) => self.exit_on_quit(),
+        }
+    }
+
+    /// Runs `f` with no quit held, even one left over from `wait_or_quit`, so that reads
+    /// which can't hand a quit back see it handled by `check_quit` as usual. Should one
+    /// still come back, the caller can only end the program with `exit_on_quit`.
+    fn unheld<R>(&self, f: impl FnOnce() -> R) -> R {
+        let held = self.hold_quit.replace(false);
+        let result = f();
+        self.hold_quit.set(held);
+        result
+    }
+
+    /// Like `wait_raw`, but gives up as configured (e.g. by `abort_on_repeat`)
+    fn try_wait_raw<T, F>(&self, p: &str, parse: F) -> Result<T, InputError>
+    where
+        F: Fn(&str) -> Result<T, Option<String>>,
+    {
+        self.fallibly(|| self.wait_loop(p, parse, true))
+    }
+
+    /// Runs `f` with IO errors kept for `read_failure` to report, instead of printed and
+    /// carried on from
+    fn fallibly<R>(&self, f: impl FnOnce() -> R) -> R {
+        let was = self.fallible.replace(true);
+        let result = f();
+        self.fallible.set(was);
+        result
+    }
+
+    /// Takes the reason the last read produced no input, if it failed or hit the end
+    fn read_failure(&self) -> Option<InputError> {
+        match self.read_error.take() {
+            Some(e) => Some(InputError::Io(e)),
+            None if self.interrupted.get() => Some(InputError::Interrupted),
+            None if self.timed_out.get() => Some(InputError::TimedOut),
+            None => self.eof.get().then_some(InputError::Eof),
+        }
+    }
+
+    /// Asks with `p` until `parse` accepts the input, or the quit trigger is entered
+    /// while held by `wait_or_quit`, or (if `can_fail`) it's time to give up
+    fn wait_loop<T, F>(&self, p: &str, parse: F, can_fail: bool) -> Result<T, InputError>
+    where
+        F: Fn(&str) -> Result<T, Option<String>>,
+    {
+        let mut attempts = 0;
+        let mut failures = 0;
+        let mut repeats: Option<(String, usize)> = None;
+
+        loop {
+            self.attempt.set(attempts + 1);
+            let response = self.get_raw_data(p, &parse);
+            attempts += 1;
+
+            if let Ok(value) = response {
+                self.attempt.set(0);
+                if let Some(answer) = &self.last_input.borrow().1 {
+                    self.echo_answer(p, answer);
+                }
+                self.print_success();
+                self.log_interaction(p, attempts);
+                self.accepted();
+                return Ok(value);
+            }
+
+            if let Some(e) = self.read_failure().filter(|_| can_fail) {
+                self.attempt.set(0);
+                self.log_interaction(p, attempts);
+                return Err(e);
+            }
+            if self.interrupted.get() {
+                // Just ask again; the ^C the terminal echoed says enough
+                continue;
+            }
+            if self.quit_flag.get() {
+                if self.hold_quit.get() || (can_fail && self.soft_quit) {
+                    self.attempt.set(0);
+                    self.log_interaction(p, attempts);
+                    return Err(InputError::Quit);
+                }
+                repeats = None;
+            } else {
+                failures += 1;
+                if can_fail && self.abort_repeats > 0 {
+                    let raw = self.last_input.borrow().0.clone();
+                    let times = match repeats.take() {
+                        Some((last, times)) if last == raw => times + 1,
+                        _ => 1,
+                    };
+                    if times >= self.abort_repeats {
+                        self.attempt.set(0);
+                        self.log_interaction(p, attempts);
+                        return Err(InputError::Repeated { input: raw, times });
+                    }
+                    repeats = Some((raw, times));
+                }
+            }
+            self.check_error(&response);
+
+            if can_fail && self.max_attempts > 0 && failures >= self.max_attempts {
+                self.attempt.set(0);
+                self.log_interaction(p, attempts);
+                return Err(InputError::Exhausted { attempts: failures });
+            }
+        }
+    }
+
+    /// Writes a record of the interaction that just finished to the `json_transcript`
+    #[cfg_attr(not(feature = "json"), allow(unused_variables))]
+    fn log_interaction(&self, p: &str, attempts: usize) {
+        #[cfg(feature = "json")]
+        if let Some(sink) = &self.transcript {
+            let (raw_input, parsed) = &*self.last_input.borrow();
+            let (raw_input, parsed) = match self.hidden.get() {
+                true => ("", None),
+                false => (raw_input.as_str(), parsed.as_deref()),
+            };
+            let record = json::Record {
+                prompt: p,
+                raw_input,
+                parsed,
+                attempts,
+                quit: self.quit_flag.get(),
+            };
+            let mut w = sink.0.borrow_mut();
+            let _ = writeln!(w, "{}", record.to_json()).and_then(|_| w.flush());
+        }
+    }
+
+    /// Checks if the user's input is the quit trigger, and if so, ends the program
+    /// (or with `soft_quit`, records it and returns `true`)
+    fn check_quit(&self, message: &str) -> bool {
+        if self.is_quit(message) {
+            self.quit_flag.set(true);
+            if !self.soft_quit && !self.hold_quit.get() {
+                self.exit_on_quit();
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Ends the program after a quit trigger, showing the `quit_msg` first
+    fn exit_on_quit(&self) -> ! {
+        if let Some(msg) = &self.quit_msg {
+            self.write_line(msg);
+        }
+        // Anything the program printed without a newline would be lost otherwise
+        let _ = stdout().flush();
+        std::process::exit(0);
+    }
+
+    /// Gets the hint on how to quit for input that looks like an attempt to, if no
+    /// `quit` trigger has been set
+    fn exit_hint_for(&self, line: &str) -> Option<String> {
+        let word = line.trim().to_lowercase();
+        let quit_like = matches!(word.as_str(), "quit" | "exit" | "q");
+
+        (quit_like && self.user_quit.is_empty() && !self.no_exit_hint)
+            .then(|| "Hint: to quit, press Ctrl-C".to_owned())
+    }
+
+    /// Checks whether `message` matches one of the quit triggers
+    fn is_quit(&self, message: &str) -> bool {
+        let message = message.trim();
+        self.user_quit
+            .iter()
+            .any(|trigger| match self.quit_ignore_case {
+                true => self
+                    .quit_match
+                    .matches(&trigger.to_lowercase(), &message.to_lowercase()),
+                false => self.quit_match.matches(trigger, message),
+            })
+    }
+
+    /// Checks whether `response` was entered incorrectly, and if so, prints the error message
+    /// (preferring the reason it was rejected with, if any)
+    fn check_error<T>(&self, response: &Result<T, Option<String>>) {
+        if let Err(reason) = response {
+            if self.quit_flag.get() {
+                return;
+            }
+            let custom = match (reason, &self.err_with, &self.user_errmsg) {
+                (Some(_), _, _) => None,
+                (None, Some(f), _) => Some((f.0)(self.last_input.borrow().0.trim())),
+                (None, None, Some(msg)) => Some(self.render_template(msg)),
+                (None, None, None) => None,
+            };
+            match (reason, custom, &self.example) {
+                (Some(reason), _, _) => self.print_error(reason),
+                (None, Some(msg), example) => {
+                    self.print_error(&msg);
+                    if let Some(example) = example {
+                        self.print_error(&format!("Example: {}", example));
+                    }
+                }
+                (None, None, Some(example)) => {
+                    self.print_error(&format!("Invalid. Example: {}", example));
+                }
+                (None, None, None) => {}
+            }
+            if let Some(hook) = &self.on_error {
+                let attempt = self.attempt.get().max(1);
+                (hook.0)(attempt, self.last_input.borrow().0.trim());
+            }
+        }
+    }
+
+    /// Prints an error message, in the error color if enabled
+    fn print_error(&self, msg: &str) {
+        let msg = self.paint(self.error_color.or(Some(Color::Red)), msg);
+        // Not through handle_io, which reports its own errors here
+        let _ = self.emit(&format!("{}\n", msg));
+    }
+
+    /// Repeats the accepted `answer` to `p` back to the user, if `echo` is set
+    fn echo_answer(&self, p: &str, answer: &str) {
+        if self.echo_answers && !self.hidden.get() {
+            let line = format!("\u{2713} {} {}", p.trim_end(), answer);
+            self.write_line(&self.paint(self.success_color, &line));
+        }
+    }
+
+    /// Prints the `success_msg`, if one is set, in the success color if enabled
+    fn print_success(&self) {
+        if let Some(msg) = &self.success_msg {
+            self.write_line(&self.paint(self.success_color.or(Some(Color::Green)), msg));
+        }
+    }
+
+    /// Writes `text` to the output (the `with_writer` writer, or stdout or stderr) in one go and
+    /// flushes it, holding the output throughout so nothing printed elsewhere lands in
+    /// the middle
+    fn emit(&self, text: &str) -> std::io::Result<()> {
+        match &self.writer {
+            Some(sink) => {
+                let mut w = sink.0.borrow_mut();
+                w.write_all(text.as_bytes())?;
+                w.flush()
+            }
+            None if self.to_stderr => {
+                let mut out = std::io::stderr().lock();
+                out.write_all(text.as_bytes())?;
+                out.flush()
+            }
+            None => {
+                let mut out = stdout().lock();
+                out.write_all(text.as_bytes())?;
+                out.flush()
+            }
+        }
+    }
+
+    /// Writes `text` to the output
+    fn write_out(&self, text: &str) {
+        let _ = self.handle_io(|| self.emit(text));
+    }
+
+    /// Writes `text` and a line break to the output
+    fn write_line(&self, text: &str) {
+        self.write_out(&format!("{}\n", text));
+    }
+
+    /// Colors `text` if `color` is set and coloring is enabled and supported (or forced)
+    fn paint<'a>(&self, color: Option<Color>, text: &'a str) -> Cow<'a, str> {
+        let enabled = self.force_color
+            || (self.color && self.writer.is_none() && color::supported(self.to_stderr));
+        match color {
+            Some(c) if enabled => Cow::Owned(c.paint(text)),
+            _ => Cow::Borrowed(text),
+        }
+    }
+
+    /// Handles getting data from the user, displaying `p` and handing the trimmed
+    /// input (see `keep_whitespace`) to `parse`
+    fn get_data<T, F>(&self, p: &str, parse: F) -> Option<T>
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        let response = self
+            .get_raw_data(p, |s| parse(self.strip(s)).ok_or(None))
+            .ok();
+        self.log_interaction(p, 1);
+        if response.is_some() {
+            self.accepted();
+        }
+        response
+    }
+
+    /// Like `get_data`, but only strips the line ending before handing the input to `parse`
+    fn get_raw_data<T, F>(&self, p: &str, parse: F) -> Result<T, Option<String>>
+    where
+        F: Fn(&str) -> Result<T, Option<String>>,
+    {
+        let mut buffer = String::new();
+        let quit = self.read_input(Some(p), &mut buffer);
+        let raw = buffer.trim_end_matches(['\n', '\r']);
+        if quit || self.interrupted.get() || (self.fallible.get() && self.read_error.is_set()) {
+            self.note_input(raw, None);
+            return Err(None);
+        }
+        self.check_line(raw, parse)
+    }
+
+    /// Fills in the default, applies `collapse_whitespace`, `input_map` and the length
+    /// limits to the line `raw` just read, then hands it to `parse`
+    fn check_line<T, F>(&self, raw: &str, parse: F) -> Result<T, Option<String>>
+    where
+        F: Fn(&str) -> Result<T, Option<String>>,
+    {
+        let line = self.collapse(self.fill_default(raw));
+        let line = match &self.input_map {
+            Some(map) => Cow::Owned((map.0)(self.strip(&line).to_owned())),
+            None => line,
+        };
+        let len = self.strip(&line).chars().count();
+        let too_long = self.max_len.filter(|&max| len > max).map(|max| {
+            format!(
+                "Too long ({}/{}); please enter at most {} characters",
+                len, max, max
+            )
+        });
+        let too_short = self.min_len.filter(|&min| len < min).map(|min| {
+            format!(
+                "Too short ({}/{}); please enter at least {} characters",
+                len, min, min
+            )
+        });
+        if let Some(msg) = too_long.or(too_short) {
+            self.note_input(raw, None);
+            return Err(Some(msg));
+        }
+        let mut line = line;
+        let mut response = parse(&line);
+        if let (Err(_), Some(plain)) = (&response, self.ungroup_digits(&line)) {
+            if let Ok(value) = parse(&plain) {
+                line = Cow::Owned(plain);
+                response = Ok(value);
+            }
+        }
+        let response = response.map_err(|reason| reason.or_else(|| self.exit_hint_for(&line)));
+        self.note_input(raw, response.as_ref().ok().map(|_| self.strip(&line)));
+        response
+    }
+
+    /// Keeps the line just read (and the text accepted from it) for the `json_transcript`
+    /// and `on_accept`
+    fn note_input(&self, raw: &str, accepted: Option<&str>) {
+        self.last_input
+            .replace((raw.to_owned(), accepted.map(str::to_owned)));
+    }
+
+    /// Passes the text accepted by the last read to the `on_accept` hook, and keeps it as
+    /// the next default for `sticky_default` and `remember`
+    fn accepted(&self) {
+        if let Some(line) = self.last_input.borrow().1.as_deref() {
+            self.remember_default(line);
+        }
+        self.accepted_choice();
+    }
+
+    /// Like `accepted`, for a yes/no answer or menu pick, which isn't kept as a default
+    /// since it means nothing to the reads that follow
+    fn accepted_choice(&self) {
+        let line = self.last_input.borrow().1.clone();
+        let Some(line) = line else {
+            return;
+        };
+        if self.record_history && !self.hidden.get() {
+            self.history.borrow_mut().push(line.clone());
+        }
+        if let Some(hook) = &self.on_accept {
+            (hook.0)(&line);
+        }
+    }
+
+    /// Displays `p` (if any) and reads a line from the user into `buffer`, returning
+    /// whether it was the quit trigger
+    fn read_input(&self, p: Option<&str>, buffer: &mut String) -> bool {
+        self.quit_flag.set(false);
+        self.eof.set(false);
+        self.timed_out.set(false);
+        self.interrupted.set(false);
+        self.read_error.take();
+        loop {
+            let shown = self.prompt_shown.take();
+            if let Some(p) = p.filter(|_| !shown && self.shows_prompt()) {
+                self.write_out(&self.render_prompt(p));
+                self.prompted.set(true);
+                if self.fallible.get() && self.read_error.is_set() {
+                    // No point waiting for an answer to a prompt that couldn't be shown
+                    return false;
+                }
+            }
+            match self.handle_io(|| self.read_lines(buffer)) {
+                Ok(0) if !self.timed_out.get() && !self.interrupted.get() => self.eof.set(true),
+                Ok(_) => {}
+                Err(e) => self.read_error.set(e),
+            }
+            if self.println_after {
+                self.write_line("");
+            }
+            if self.sanitize {
+                *buffer = parse::sanitize(buffer);
+            }
+
+            match &self.help {
+                Some((text, keyword)) if keyword == buffer.trim() => {
+                    self.write_line(text);
+                    buffer.clear();
+                }
+                _ if self.never_exit && self.is_quit(buffer) => buffer.clear(),
+                _ => return self.check_quit(buffer),
+            }
+        }
+    }
+
+    /// Checks whether the prompt should be shown for this read, which it isn't once
+    /// `prompt_once` has shown it, or on a retry without `reprompt`
+    fn shows_prompt(&self) -> bool {
+        let retry = self.no_reprompt && self.attempt.get() > 1;
+        let once = self.prompt_once && self.prompted.get();
+        !retry && !once
+    }
+
+    /// Shows `p` (if it should be) ahead of the next read, which then doesn't show it
+    /// again, and waits without blocking until a line of stdin is ready for it. Lines
+    /// from any other source are read as usual.
+    #[cfg(feature = "async")]
+    async fn prompt_ahead(&self, p: &str) {
+        if self.shows_prompt() {
+            self.write_out(&self.render_prompt(p));
+            self.prompted.set(true);
+        }
+        self.prompt_shown.set(true);
+        if self.answers.is_none() && self.sources.is_none() && self.reader.is_none() {
+            poll::LineReady.await;
+        }
+    }
+
+    /// Reads the next line of input into `buffer`, or with `multiline`, every line up to
+    /// the terminator (or the end of input), returning how much was read
+    fn read_lines(&self, buffer: &mut String) -> std::io::Result<usize> {
+        let Some(terminator) = &self.multiline else {
+            return self.read_line(buffer);
+        };
+
+        let start = buffer.len();
+        let mut read = 0;
+        loop {
+            let mut line = String::new();
+            let n = self.read_line(&mut line)?;
+            read += n;
+            if n == 0 || line.trim_end_matches(['\n', '\r']) == terminator {
+                return Ok(read);
+            }
+
+            // The quit trigger still works, as the first line
+            let quit = buffer.len() == start && self.is_quit(&line);
+            buffer.push_str(&line);
+            if quit {
+                return Ok(read);
+            }
+        }
+    }
+
+    /// Reads the next line of input into `buffer`, starting with any line `peek_line`
+    /// held back
+    fn read_line(&self, buffer: &mut String) -> std::io::Result<usize> {
+        let peeked = self.peeked.borrow_mut().take();
+        let (line, scripted) = match peeked {
+            Some(peeked) => peeked,
+            None => self.next_line()?,
+        };
+
+        if scripted && self.hidden.get() {
+            self.write_line("");
+        } else if scripted {
+            self.write_line(line.trim_end_matches(['\n', '\r']));
+        }
+        buffer.push_str(&line);
+        Ok(line.len())
+    }
+
+    /// Fetches the next line of input (with its line ending), from the `answers_file`
+    /// and then each `chain_source` while they have lines left, along with whether it
+    /// came from one of those rather than the user
+    fn next_line(&self) -> std::io::Result<(String, bool)> {
+        if let Some(line) = self.next_scripted_line()? {
+            return Ok((line, true));
+        }
+
+        let mut line = String::new();
+        if self.reads_user() {
+            match &self.reader {
+                Some(reader) => {
+                    reader.0.borrow_mut().read_line(&mut line)?;
+                }
+                None if self.hidden.get() => {
+                    let no_echo = echo::NoEcho::start();
+                    self.read_user_line(&mut line)?;
+                    if no_echo.is_hiding() {
+                        // The Enter ending the line wasn't echoed either
+                        self.write_line("");
+                    }
+                }
+                None => self.read_user_line(&mut line)?,
+            }
+        }
+        Ok((line, false))
+    }
+
+    /// Fetches the next line (with its line ending) from the `answers_file` or a
+    /// `chain_source`, if any of them has one left
+    fn next_scripted_line(&self) -> std::io::Result<Option<String>> {
+        if let Some(answers) = &self.answers {
+            if let Some(line) = answers.borrow_mut().pop_front() {
+                return Ok(Some(line + "\n"));
+            }
+        }
+
+        if let Some(sources) = &self.sources {
+            let mut sources = sources.0.borrow_mut();
+            while let Some(source) = sources.front_mut() {
+                let mut line = String::new();
+                if source.read_line(&mut line)? > 0 {
+                    return Ok(Some(line));
+                }
+                sources.pop_front();
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether reads go on to the reader or stdin once the scripted lines run out
+    fn reads_user(&self) -> bool {
+        !self.answers_only || (self.answers.is_none() && self.sources.is_none())
+    }
+
+    /// Reads a line typed by the user on stdin, giving up once the `timeout` (if any)
+    /// passes, which leaves `line` empty
+    fn read_user_line(&self, line: &mut String) -> std::io::Result<()> {
+        let catch = interrupt::Catch::start(self.on_interrupt);
+        if self.on_interrupt == InterruptMode::ReturnErr {
+            self.read_interruptible(line, &catch);
+            return Ok(());
+        }
+        let max = self.max_len.filter(|_| self.live_count);
+        if (max.is_some() || self.record_history) && self.reads_by_key() {
+            if let Some(_keys) = echo::NoEcho::by_key() {
+                let history = self.history.borrow().clone();
+                line.push_str(&live::read_line(max, &history, |s| self.write_out(s))?);
+                return Ok(());
+            }
+        }
+
+        match self.timeout {
+            Some(timeout) => {
+                if poll::read_line_timeout(line, timeout).is_none() {
+                    self.timed_out.set(true);
+                    // Whatever comes next starts on a line of its own
+                    self.write_line("");
+                }
+            }
+            None => {
+                poll::read_line(line)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether stdin can be read key by key, for `max_len_live` or to recall history
+    fn reads_by_key(&self) -> bool {
+        let shown_on_terminal = self.writer.is_none()
+            && match self.to_stderr {
+                true => std::io::stderr().is_terminal(),
+                false => stdout().is_terminal(),
+            };
+        let by_line = self.hidden.get() || self.timeout.is_some() || poll::started();
+
+        shown_on_terminal && !by_line
+    }
+
+    /// Like `read_user_line`, but checks for Ctrl-C every so often while waiting,
+    /// giving up (leaving `line` empty) once it's pressed
+    fn read_interruptible(&self, line: &mut String, catch: &interrupt::Catch) {
+        const CHECK_EVERY: Duration = Duration::from_millis(50);
+
+        let deadline = self.timeout.map(|t| std::time::Instant::now() + t);
+        loop {
+            let wait = match deadline {
+                Some(deadline) => deadline
+                    .saturating_duration_since(std::time::Instant::now())
+                    .min(CHECK_EVERY),
+                None => CHECK_EVERY,
+            };
+            if poll::read_line_timeout(line, wait).is_some() {
+                return;
+            }
+
+            if catch.take() {
+                self.interrupted.set(true);
+            } else if deadline.is_some_and(|d| std::time::Instant::now() >= d) {
+                self.timed_out.set(true);
+            } else {
+                continue;
+            }
+            // Whatever comes next starts on a line of its own
+            self.write_line("");
+            return;
+        }
+    }
+
+    /// Trims the whitespace around `line`, unless `keep_whitespace` is set (the line ending
+    /// is already gone by then)
+    fn strip<'a>(&self, line: &'a str) -> &'a str {
+        match self.keep_whitespace {
+            true => line,
+            false => line.trim(),
+        }
+    }
+
+    /// Squeezes each run of whitespace in `line` down to one space when
+    /// `collapse_whitespace` is set
+    fn collapse<'a>(&self, line: Cow<'a, str>) -> Cow<'a, str> {
+        if self.collapse_whitespace {
+            Cow::Owned(line.split_whitespace().collect::<Vec<_>>().join(" "))
+        } else {
+            line
+        }
+    }
+
+    /// With `lenient_numbers` set, the input without its digit grouping if it's a number
+    /// like `1,000`, `1 000` or `12_345.5`: a group of 1 to 3 digits, then groups of
+    /// exactly 3, each after a single comma, space or underscore
+    fn ungroup_digits(&self, line: &str) -> Option<String> {
+        if !self.lenient_numbers {
+            return None;
+        }
+        let line = self.strip(line);
+        let (sign, rest) = match line.strip_prefix(['+', '-']) {
+            Some(rest) => (&line[..1], rest),
+            None => ("", line),
+        };
+        let (whole, fraction) = match rest.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (rest, None),
+        };
+
+        let mut groups = whole.split([',', ' ', '_']);
+        let first = groups.next()?;
+        let mut digits = first.to_owned();
+        let mut grouped = false;
+        let is_digits = |g: &str| g.bytes().all(|b| b.is_ascii_digit());
+        if !(1..=3).contains(&first.len()) || !is_digits(first) {
+            return None;
+        }
+        for group in groups {
+            if group.len() != 3 || !is_digits(group) {
+                return None;
+            }
+            digits.push_str(group);
+            grouped = true;
+        }
+        if !grouped {
+            return None;
+        }
+
+        match fraction {
+            Some(fraction) => Some(format!("{}{}.{}", sign, digits, fraction)),
+            None => Some(format!("{}{}", sign, digits)),
+        }
+    }
+
+    /// Swaps empty input for the remembered `sticky_default` answer or a default, if
+    /// there is one
+    fn fill_default<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        if line.trim().is_empty() {
+            if let Some(default) = self.current_default() {
+                return Cow::Owned(default);
+            }
+        }
+        Cow::Borrowed(line)
+    }
+
+    /// The answer empty input stands for right now: the remembered `sticky_default`
+    /// answer, falling back to the `default_value` and then the `default_fn`
+    fn current_default(&self) -> Option<String> {
+        let sticky = self.sticky_value.borrow().clone();
+        sticky
+            .or_else(|| self.default_value.clone())
+            .or_else(|| self.default_fn.as_ref().map(|f| (f.0)()))
+    }
+
+    /// Records an accepted answer, for `sticky_default` and `remember`
+    fn remember_default(&self, line: &str) {
+        // A secret would be shown in the next prompt, and saved as it is
+        if self.hidden.get() {
+            return;
+        }
+        if self.sticky || self.memory.is_some() {
+            self.sticky_value.replace(Some(line.trim().to_owned()));
+        }
+        if let Some((key, path)) = &self.memory {
+            let _ = self.handle_io(|| store::save(path, key, line.trim()));
+        }
+    }
+
+    /// Fills in the placeholders of a user-provided prompt or message
+    fn render_template(&self, text: &str) -> String {
+        let attempt = self.attempt.get().max(1);
+        match self.index.get() {
+            Some(index) => template::render(text, &[("attempt", &attempt), ("index", &index)]),
+            None => template::render(text, &[("attempt", &attempt)]),
+        }
+    }
+
+    /// Builds the text actually shown for the prompt `p`
+    fn render_prompt(&self, p: &str) -> String {
+        let prompt = self.render_template(p);
+        let prompt = match &self.prompt_suffix {
+            Some(suffix) if !prompt.trim().is_empty() => {
+                let head = prompt.trim_end();
+                let head = head.strip_suffix(suffix.trim_end()).unwrap_or(head);
+                format!("{}{}", head.trim_end(), suffix)
+            }
+            _ if self.auto_suffix && !prompt.trim().is_empty() => {
+                format!("{} ", prompt.trim_end())
+            }
+            _ => prompt,
+        };
+        let shown = match self.sticky_value.borrow().as_ref() {
+            Some(sticky) => Some(sticky.clone()),
+            None => self.default_value.clone().filter(|_| !self.hide_default),
+        };
+        let prompt = match shown {
+            Some(default) => with_default(&prompt, &default),
+            None => prompt,
+        };
+        let prompt = match self.user_quit.as_slice() {
+            [] => prompt,
+            _ if !self.show_quit_hint => prompt,
+            [trigger] => with_note(&prompt, &format!("(type '{}' to exit)", trigger)),
+            [rest @ .., last] => {
+                let rest: Vec<String> = rest.iter().map(|t| format!("'{}'", t)).collect();
+                let note = format!("(type {} or '{}' to exit)", rest.join(", "), last);
+                with_note(&prompt, &note)
+            }
+        };
+
+        #[cfg(feature = "tty")]
+        let prompt = match term::width(self.to_stderr).filter(|_| self.writer.is_none()) {
+            Some(width) if !self.no_wrap => term::wrap(&prompt, width),
+            _ => prompt,
+        };
+
+        let prompt = match &self.decorator {
+            Some(decorate) => (decorate.0)(&prompt),
+            None => prompt,
+        };
+
+        self.paint(self.prompt_color, &prompt).into_owned()
+    }
+
+    /// Handles [std::io] operations; will simply print that an error
+    /// occurred and continue on, handing the result back for callers that care
+    ///
+    /// An operation that's merely interrupted is retried, up to `IO_RETRIES` times,
+    /// before that.
+    fn handle_io<T, F>(&self, mut io: F) -> std::io::Result<T>
+    where
+        F: FnMut() -> std::io::Result<T>,
+    {
+        const IO_RETRIES: usize = 3;
+
+        let mut result = io();
+        for _ in 0..IO_RETRIES {
+            match &result {
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => result = io(),
+                _ => break,
+            }
+        }
+
+        match &result {
+            Err(e) if self.fallible.get() => {
+                if !self.read_error.is_set() {
+                    self.read_error
+                        .set(std::io::Error::new(e.kind(), e.to_string()));
+                }
+            }
+            // Whoever was reading the output is gone (e.g. `prog | head`), so there's no
+            // one left to prompt
+            Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe && !self.never_exit => {
+                std::process::exit(0)
+            }
+            Err(e) => self.print_error(&format!("IO Error: {}; Continuing...", e)),
+            Ok(_) => {}
+        }
+        result
+    }
+
+    /// Like `read::<String>`, but reuses an internal buffer instead of allocating
+    /// a new `String` for every line.
+    ///
+    /// The returned text is usually borrowed straight from that buffer. It's only owned
+    /// when it had to be replaced, e.g. by a `sticky_default` answer. Because the next
+    /// read overwrites the buffer, this takes `&mut self`: the returned value has to be
+    /// dropped (or converted with [Cow::into_owned]) before this Input can read again.
+    ///
+    /// Returns `None` if a `soft_quit` trigger was entered.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new()
+    ///     .quit("done")
+    ///     .soft_quit(true)
+    ///     .with_reader(&b"apple\nfig\ndone\n"[..]);
+    /// let mut total = 0;
+    ///
+    /// input.prompt("Word: ");
+    /// while let Some(word) = input.read_cow() {
+    ///     total += word.len();
+    /// }
+    ///
+    /// assert_eq!(total, 8);
+    /// ```
+    pub fn read_cow(&mut self) -> Option<Cow<'_, str>> {
+        let mut buffer = std::mem::take(&mut self.line_buf);
+        buffer.clear();
+        let quit = self.read_input(Some(&self.user_prompt), &mut buffer);
+        self.line_buf = buffer;
+
+        let raw = self.line_buf.trim_end_matches(['\n', '\r']);
+        if quit {
+            self.note_input(raw, None);
+            self.log_interaction(&self.user_prompt, 1);
+            return None;
+        }
+
+        let line = self.collapse(self.fill_default(self.line_buf.trim()));
+        self.note_input(raw, Some(&line));
+        self.log_interaction(&self.user_prompt, 1);
+        self.accepted();
+        Some(line)
+    }
+
+    /// Returns the next line of input without consuming it, or `None` once input has ended.
+    ///
+    /// No prompt is shown. The line is held back so that the next read on this Input
+    /// (or any clone of it), such as `read` or `wait`, receives it as its input. Peeking
+    /// again before then returns the same line.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let mut input = Input::new().chain_source(Box::new(Cursor::new("# header\n42\n")));
+    ///
+    /// if input.peek_line().is_some_and(|l| l.starts_with('#')) {
+    ///     let header: String = input.prompt("").wait();
+    ///     assert_eq!(header, "# header");
+    /// }
+    /// assert_eq!(input.peek_line().as_deref(), Some("42"));
+    /// assert_eq!(input.prompt("Value: ").read(), Some(42));
+    /// ```
+    pub fn peek_line(&self) -> Option<String> {
+        let mut peeked = self.peeked.borrow_mut();
+
+        if peeked.is_none() {
+            let mut next = None;
+            let _ = self.handle_io(|| self.next_line().map(|l| next = Some(l)));
+            *peeked = next.filter(|(line, _)| !line.is_empty());
+        }
+
+        let (line, _) = peeked.as_ref()?;
+        Some(line.trim_end_matches(['\n', '\r']).to_owned())
+    }
+
+    /// Returns whether the next line read will come from someone at a terminal, for
+    /// callers that act differently when nobody's there to answer.
+    ///
+    /// This is `false` while lines remain in an `answers_file` or `chain_source` (or
+    /// once they run out, if [Input::interactive_fallback] is off), and otherwise
+    /// whether stdin is a terminal (never, with [Input::with_reader]).
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::{stdin, Cursor, IsTerminal};
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("Ada\n")));
+    /// assert!(!input.is_interactive());
+    ///
+    /// // Once the source is used up, it's down to stdin
+    /// let name: String = input.wait();
+    /// assert_eq!(input.is_interactive(), stdin().is_terminal());
+    /// ```
+    pub fn is_interactive(&self) -> bool {
+        if let Some((_, scripted)) = self.peeked.borrow().as_ref() {
+            return !scripted;
+        }
+
+        let answers_left = self
+            .answers
+            .as_ref()
+            .is_some_and(|a| !a.borrow().is_empty());
+        let sources_left = self.sources.as_ref().is_some_and(|sources| {
+            let mut sources = sources.0.borrow_mut();
+            while let Some(source) = sources.front_mut() {
+                if !matches!(source.fill_buf(), Ok(buf) if buf.is_empty()) {
+                    return true;
+                }
+                sources.pop_front();
+            }
+            false
+        });
+        let scripted = self.answers.is_some() || self.sources.is_some();
+
+        if answers_left || sources_left || (scripted && self.answers_only) {
+            return false;
+        }
+        self.reader.is_none() && std::io::stdin().is_terminal()
+    }
+
+    /// Returns the user's input if they've already entered a complete line, without
+    /// waiting for them otherwise.
+    ///
+    /// Returns `None` if no line is available yet, and `Some` with the result of
+    /// parsing it to `T` if one is. No prompt is printed, since this is meant to be
+    /// polled, e.g. from a game loop. Otherwise the line is read like `read` reads it:
+    /// the `quit` trigger applies, and a line that parses but breaks a rule such as
+    /// [Input::max_len] or [Input::validate] has its error shown, then gives `None`,
+    /// as no usable line has arrived.
+    ///
+    /// The first call starts a background thread that reads stdin for the rest of the
+    /// program, as described on [Input]. When stdin is a terminal, lines only arrive once the user presses Enter, and a
+    /// line still being typed is never returned partially. Once stdin reaches its end,
+    /// this always returns `None`.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let input = Input::new().quit("quit");
+    ///
+    /// loop {
+    ///     match input.try_read_now::<i32>() {
+    ///         Some(Ok(n)) => println!("You entered {}", n),
+    ///         Some(Err(e)) => println!("That's not a number: {}", e),
+    ///         None => { /* nothing typed yet; keep running the game */ }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Lines already waiting, from [Input::peek_line], `scripted` answers or a
+    /// `chain_source`, are taken first, while nothing waiting gives `None`:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new()
+    ///     .scripted(vec!["3".to_string()])
+    ///     .chain_source(Box::new(Cursor::new("5\nsix\n")))
+    ///     .with_reader(&b""[..]);
+    ///
+    /// assert_eq!(input.try_read_now::<i32>(), Some(Ok(3)));
+    /// assert_eq!(input.peek_line().as_deref(), Some("5"));
+    /// assert_eq!(input.try_read_now::<i32>(), Some(Ok(5)));
+    /// assert!(matches!(input.try_read_now::<i32>(), Some(Err(_))));
+    /// assert_eq!(input.try_read_now::<i32>(), None);
+    /// ```
+    pub fn try_read_now<T>(&self) -> Option<Result<T, T::Err>>
+    where
+        T: std::str::FromStr,
+    {
+        self.quit_flag.set(false);
+        // The same order `read_line` takes lines in, but never waiting on stdin
+        let peeked = self.peeked.borrow_mut().take();
+        let scripted = match peeked {
+            Some((line, _)) => Some(line),
+            None => self.next_scripted_line().ok()?,
+        };
+        let line = match scripted {
+            Some(line) => line,
+            None if !self.reads_user() => return None,
+            None => match &self.reader {
+                Some(reader) => {
+                    let mut line = String::new();
+                    if reader.0.borrow_mut().read_line(&mut line).ok()? == 0 {
+                        return None;
+                    }
+                    line
+                }
+                None => poll::try_line()?,
+            },
+        };
+        let line = match self.sanitize {
+            true => parse::sanitize(&line),
+            false => line,
+        };
+        let raw = line.trim_end_matches(['\n', '\r']);
+        if self.never_exit && self.is_quit(raw) {
+            return None;
+        }
+        if self.check_quit(raw) {
+            self.note_input(raw, None);
+            self.log_interaction(&self.user_prompt, 1);
+            return None;
+        }
+
+        // Kept aside so that input which doesn't parse still gives `T::Err`
+        let parse_error = Cell::new(None);
+        let response = self.check_line(raw, |s| {
+            let s = self.strip(s);
+            match self.parse_str::<T>(s) {
+                Ok(_) => {
+                    parse_error.set(None);
+                    self.parse_valid(s)
+                }
+                Err(e) => {
+                    parse_error.set(Some(e));
+                    Err(None)
+                }
+            }
+        });
+        self.log_interaction(&self.user_prompt, 1);
+        match (response, parse_error.into_inner()) {
+            (Ok(value), _) => {
+                self.accepted();
+                Some(Ok(value))
+            }
+            (Err(_), Some(e)) => Some(Err(e)),
+            (Err(reason), None) => {
+                self.check_error(&Err::<T, _>(reason));
+                None
+            }
+        }
+    }
+
+    /// Presents a series of options to the user from which they can choose one.
+    ///
+    /// This function will guarantee that the user chooses something present in `opts`.
+    /// With just one option and [Input::auto_select_single], it's chosen without asking.
+    ///
+    /// Panics if `opts` is empty, since nothing could ever be chosen.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let choice = Input::new()
+    ///     .wait_opts(&["First", "Second", "Third"], "Enter your choice: ");
+    ///
+    /// match choice {
+    ///     "First" => println!("1st!"),
+    ///     "Second" => println!("2nd"),
+    ///     "Third" => println!("3rd.."),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    ///
+    /// The user in the above case would see the following:
+    /// ```markdown
+    /// 1. First
+    /// 2. Second
+    /// 3. Third
+    /// Enter your choice:
+    /// ```
+    ///
+    /// Besides an option's number, the user can type the option itself, ignoring case:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("maybe\nyes\n2\n")));
+    ///
+    /// // "maybe" matches nothing, so the menu is shown again
+    /// assert_eq!(input.wait_opts(&["Yes", "No"], "Choice: "), "Yes");
+    /// assert_eq!(input.wait_opts(&["Yes", "No"], "Choice: "), "No");
+    /// ```
+    ///
+    /// The options only need to be displayable, since the answer is always read as a
+    /// selection rather than parsed as a `T`:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::fmt;
+    /// use std::io::Cursor;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// struct Server {
+    ///     host: &'static str,
+    ///     port: u16,
+    /// }
+    ///
+    /// impl fmt::Display for Server {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{}:{}", self.host, self.port)
+    ///     }
+    /// }
+    ///
+    /// let servers = [
+    ///     Server { host: "eu.example.com", port: 443 },
+    ///     Server { host: "us.example.com", port: 8443 },
+    /// ];
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("2\n")));
+    ///
+    /// assert_eq!(input.wait_opts(&servers, "Server: "), servers[1]);
+    /// ```
+    pub fn wait_opts<T>(&self, opts: &[T], p: &str) -> T
+    where
+        T: std::fmt::Display + Clone,
+    {
+        self.wait_opts_indexed(opts, p).1
+    }
+
+    /// Like [Input::wait_opts], but each entry pairs the label shown for it with the
+    /// value returned when it's picked, so the value needn't display as its label.
+    ///
+    /// Picking works just as for `wait_opts`: by number, or by typing the label.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum Action {
+    ///     DeleteAll,
+    ///     Archive,
+    /// }
+    ///
+    /// let entries = [
+    ///     ("Delete all files".to_owned(), Action::DeleteAll),
+    ///     ("Archive them".to_owned(), Action::Archive),
+    /// ];
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("3\narchive them\n")));
+    ///
+    /// assert_eq!(input.wait_opts_labeled(&entries, "Action: "), Action::Archive);
+    /// ```
+    ///
+    /// The user in the above case would see the following:
+    /// ```plaintext
+    /// 1. Delete all files
+    /// 2. Archive them
+    /// Action:
+    /// ```
+    pub fn wait_opts_labeled<L, T>(&self, entries: &[(L, T)], p: &str) -> T
+    where
+        L: std::fmt::Display,
+        T: Clone,
+    {
+        let labels: Vec<&L> = entries.iter().map(|(label, _)| label).collect();
+        let (index, _) = self.wait_opts_indexed(&labels, p);
+        entries[index].1.clone()
+    }
+
+    /// Like [Input::wait_opts], but also returns the zero-based index of the option the
+    /// user picked, e.g. to look it up in a table of handlers.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let actions: [fn() -> &'static str; 2] = [|| "saved", || "discarded"];
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("2\n")));
+    /// let (index, label) = input.wait_opts_indexed(&["Save", "Discard"], "Choice: ");
+    ///
+    /// assert_eq!((index, label), (1, "Discard"));
+    /// assert_eq!(actions[index](), "discarded");
+    /// ```
+    ///
+    /// A choice that isn't one of the options is passed to [Input::on_error]:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// let seen = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&seen);
+    /// let input = Input::new()
+    ///     .on_error(move |attempt, line| log.borrow_mut().push((attempt, line.to_owned())))
+    ///     .with_reader(&b"3\nmaybe\nsave\n"[..]);
+    ///
+    /// assert_eq!(input.wait_opts_indexed(&["Save", "Discard"], "Choice: "), (0, "Save"));
+    /// assert_eq!(*seen.borrow(), [(1, "3".to_owned()), (2, "maybe".to_owned())]);
+    /// ```
+    pub fn wait_opts_indexed<T>(&self, opts: &[T], p: &str) -> (usize, T)
+    where
+        T: std::fmt::Display + Clone,
+    {
+        assert!(!opts.is_empty(), "wait_opts needs at least one option");
+        match self.unheld(|| self.opts_loop(opts, p, false)) {
+            Ok(choice) => choice,
+            Err(_) => self.exit_on_quit(),
+        }
+    }
+
+    /// Like [Input::wait_opts_indexed], but returns [InputError::NoOptions] when `opts`
+    /// is empty instead of panicking, and gives up the way [Input::try_wait] does, e.g.
+    /// with [InputError::Eof] once input ends.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// let input = Input::new().with_reader(&b"2\n"[..]);
+    /// let none: [&str; 0] = [];
+    /// let disks = ["sda", "sdb"];
+    ///
+    /// let choice = input.try_wait_opts_indexed(&none, "Disk: ");
+    /// assert!(matches!(choice, Err(InputError::NoOptions)));
+    /// let choice = input.try_wait_opts_indexed(&disks, "Disk: ");
+    /// assert!(matches!(choice, Ok((1, "sdb"))));
+    /// let choice = input.try_wait_opts_indexed(&disks, "Disk: ");
+    /// assert!(matches!(choice, Err(InputError::Eof)));
+    /// ```
+    pub fn try_wait_opts_indexed<T>(&self, opts: &[T], p: &str) -> Result<(usize, T), InputError>
+    where
+        T: std::fmt::Display + Clone,
+    {
+        if opts.is_empty() {
+            return Err(InputError::NoOptions);
+        }
+        self.fallibly(|| self.opts_loop(opts, p, true))
+    }
+
+    /// Shows the menu of `opts` until one of them is picked, or (if `can_fail`) it's time
+    /// to give up
+    fn opts_loop<T>(&self, opts: &[T], p: &str, can_fail: bool) -> Result<(usize, T), InputError>
+    where
+        T: std::fmt::Display + Clone,
+    {
+        if let [only] = opts {
+            if self.auto_select_single {
+                self.write_line(&format!("Selected {} (the only option)", only));
+                let label = only.to_string();
+                self.note_input("", Some(&label));
+                self.print_success();
+                self.accepted_choice();
+                return Ok((0, only.clone()));
+            }
+        }
+
+        /// What the user's answer to the menu asked for
+        enum Selection {
+            Pick(usize),
+            Next,
+            Previous,
+            Unknown(String),
+        }
+
+        let index;
+
+        // This is so that the input object will respect err_msg rules and quit triggers
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.echo_answers = false;
+        ic.max_len = None;
+        ic.min_len = None;
+        ic.sticky = false;
+        ic.memory = None;
+        let mut prompt = p;
+        let labels: Vec<String> = opts.iter().map(|opt| opt.to_string()).collect();
+        let size = self.page_size;
+        let pages = match size {
+            0 => 1,
+            size => opts.len().div_ceil(size).max(1),
+        };
+        let mut page = 0;
+        let mut rejected = 0;
+
+        loop {
+            if pages > 1 {
+                let start = page * size;
+                let end = (start + size).min(opts.len());
+                self.write_out(&self.render_styled(&opts[start..end], start));
+                self.write_line(&format!(
+                    "Page {} of {} (n: next, p: previous)",
+                    page + 1,
+                    pages
+                ));
+            } else {
+                self.write_out(&self.render_opts(opts));
+            }
+
+            // The answer is always read as a selection, never as a `T`, so it doesn't
+            // matter whether the options themselves can be parsed
+            let selection = ic.wait_loop(
+                prompt,
+                |s| {
+                    let s = s.trim();
+                    let lower = s.to_lowercase();
+                    // A label in range picks by position, before any option spelled the same,
+                    // and either comes before the paging keys
+                    let picked = self.option_style.pick(s, opts.len()).or_else(|| {
+                        labels
+                            .iter()
+                            .position(|label| label.to_lowercase() == lower)
+                    });
+                    Ok(match (lower.as_str(), picked) {
+                        (_, Some(i)) => Selection::Pick(i),
+                        ("n", None) if pages > 1 => Selection::Next,
+                        ("p", None) if pages > 1 => Selection::Previous,
+                        (_, None) => Selection::Unknown(s.to_owned()),
+                    })
+                },
+                can_fail,
+            )?;
+            // Input that ended reads as an empty answer, which the menu would just reject
+            if let Some(e) = ic.read_failure().filter(|_| can_fail) {
+                return Err(e);
+            }
+
+            match selection {
+                Selection::Pick(i) => {
+                    index = i;
+                    break;
+                }
+                Selection::Next => page = (page + 1).min(pages - 1),
+                Selection::Previous => page = page.saturating_sub(1),
+                Selection::Unknown(answer) => {
+                    self.print_error(&self.option_style.bounds_hint(opts.len()));
+                    if self.suggestions && answer.parse::<usize>().is_err() {
+                        let names: Vec<&str> = labels.iter().map(String::as_str).collect();
+                        if let [only] = close_to(&answer, &names, self.fuzzy_distance)[..] {
+                            self.write_line(&format!("Did you mean '{}'?", only));
+                        }
+                    }
+                    rejected += 1;
+                    if let Some(hook) = &self.on_error {
+                        (hook.0)(rejected, &answer);
+                    }
+                    prompt = self.menu_retry.as_deref().unwrap_or(p);
+                }
+            }
+        }
+
+        self.echo_answer(p, &labels[index]);
+        self.print_success();
+        self.last_input.replace(ic.last_input.take());
+        self.accepted_choice();
+        Ok((index, opts[index].clone()))
+    }
+
+    /// Formats `opts` as the menu [Input::wait_opts] shows, one option per line (each
+    /// ending in a line break) labeled as the [Input::option_style] says, without asking
+    /// anything. This is handy for help text.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let opts = ["New game", "Load game", "Quit"];
+    /// let menu = Input::new().render_opts(&opts);
+    /// assert_eq!(menu, "1. New game\n2. Load game\n3. Quit\n");
+    /// ```
+    pub fn render_opts<T: fmt::Display>(&self, opts: &[T]) -> String {
+        self.render_styled(opts, 0)
+    }
+
+    /// Formats `opts` as [Input::render_opts] does, labeling them as the ones from
+    /// `offset` on
+    fn render_styled<T: fmt::Display>(&self, opts: &[T], offset: usize) -> String {
+        opts.iter()
+            .enumerate()
+            .map(|(i, v)| self.option_style.render(offset + i, &v.to_string()) + "\n")
+            .collect()
+    }
+
+    /// Presents only the options in `opts` that `filter` accepts, and lets the user pick
+    /// any number of them by entering their numbers separated by spaces.
+    ///
+    /// The chosen options are returned in the order they were entered. If no option
+    /// passes `filter`, nothing is shown and an empty list is returned straight away.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let files = ["notes.txt", "photo.png", "todo.txt", "song.mp3"];
+    /// let picked = Input::new()
+    ///     .chain_source(Box::new(Cursor::new("2 1\n")))
+    ///     .wait_opts_matching(&files, "Open which? ", |f| f.ends_with(".txt"));
+    ///
+    /// assert_eq!(picked, ["todo.txt", "notes.txt"]);
+    /// ```
+    ///
+    /// The user in the above case would see the following:
+    /// ```plaintext
+    /// 1. notes.txt
+    /// 2. todo.txt
+    /// Open which?
+    /// ```
+    pub fn wait_opts_matching<T, F>(&self, opts: &[T], p: &str, filter: F) -> Vec<T>
+    where
+        T: std::fmt::Display + Clone,
+        F: Fn(&T) -> bool,
+    {
+        let shown: Vec<&T> = opts.iter().filter(|opt| filter(opt)).collect();
+        if shown.is_empty() {
+            return Vec::new();
+        }
+
+        self.write_out(&render_numbered(&shown, 0));
+
+        self.wait_parsed_msg(p, |s| {
+            if s.is_empty() {
+                return Err(None);
+            }
+            s.split_whitespace()
+                .map(|word| {
+                    let n: usize = word.parse().map_err(|_| None)?;
+                    match n.checked_sub(1).and_then(|i| shown.get(i)) {
+                        Some(opt) => Ok((*opt).clone()),
+                        None => Err(Some(format!(
+                            "Please enter a number within the bounds {:?}",
+                            1..=shown.len()
+                        ))),
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Presents `opts` as a numbered menu like [Input::wait_opts], and lets the user pick
+    /// several of them at once by entering their numbers, separated by commas and/or
+    /// spaces (`1,3 4`).
+    ///
+    /// The chosen options are returned in the order they were entered, each only once
+    /// however often it was given. A number out of range asks again with the bounds.
+    /// An empty line is rejected too, unless [Input::allow_empty_selection] is set.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let features = ["Logging", "Metrics", "Tracing", "Alerts"];
+    /// let picked = Input::new()
+    ///     .chain_source(Box::new(Cursor::new("1, 5\n4,1 3 1\n")))
+    ///     .wait_multi(&features, "Select features: ");
+    ///
+    /// assert_eq!(picked, ["Alerts", "Logging", "Tracing"]);
+    /// ```
+    ///
+    /// The user in the above case would see the following:
+    /// ```plaintext
+    /// 1. Logging
+    /// 2. Metrics
+    /// 3. Tracing
+    /// 4. Alerts
+    /// Select features: 1, 5
+    /// Please enter a number within the bounds 1..=4
+    /// Select features: 4,1 3 1
+    /// ```
+    pub fn wait_multi<T>(&self, opts: &[T], p: &str) -> Vec<T>
+    where
+        T: std::fmt::Display + Clone,
+    {
+        self.write_out(&render_numbered(opts, 0));
+
+        let picked: Vec<usize> = self.wait_parsed_msg(p, |s| {
+            if s.is_empty() && !self.allow_empty_selection {
+                return Err(Some("Please select at least one option".to_owned()));
+            }
+            let mut picked = Vec::new();
+            for word in s.split([',', ' ', '\t']).filter(|w| !w.is_empty()) {
+                let n: usize = word.parse().map_err(|_| None)?;
+                match n.checked_sub(1).filter(|&i| i < opts.len()) {
+                    Some(i) if picked.contains(&i) => {}
+                    Some(i) => picked.push(i),
+                    None => {
+                        return Err(Some(format!(
+                            "Please enter a number within the bounds {:?}",
+                            1..=opts.len()
+                        )))
+                    }
+                }
+            }
+            Ok(picked)
+        });
+        picked.into_iter().map(|i| opts[i].clone()).collect()
+    }
+
+    /// Sets whether [Input::wait_multi] accepts an empty line, selecting nothing (off by
+    /// default).
+    pub fn allow_empty_selection(mut self, a: bool) -> Self {
+        self.allow_empty_selection = a;
+        self
+    }
+
+    /// Presents a numbered list of on/off settings, starting from `initial`, and lets the
+    /// user flip them by entering their numbers (several at once, separated by spaces).
+    /// The list is shown again after every change, and an empty line keeps the settings
+    /// as they are, returning them.
+    ///
+    /// Panics if `labels` and `initial` aren't the same length.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let labels = ["Dark mode", "Autosave", "Telemetry"];
+    /// let states = Input::new()
+    ///     .chain_source(Box::new(Cursor::new("1 3\n2\n\n")))
+    ///     .wait_toggles(&labels, &[false, false, true], "Toggle (Enter when done): ");
+    ///
+    /// assert_eq!(states, [true, true, false]);
+    /// ```
+    ///
+    /// What the user would see at first:
+    /// ```plaintext
+    /// 1. [ ] Dark mode
+    /// 2. [ ] Autosave
+    /// 3. [x] Telemetry
+    /// Toggle (Enter when done):
+    /// ```
+    pub fn wait_toggles(&self, labels: &[&str], initial: &[bool], p: &str) -> Vec<bool> {
+        assert_eq!(
+            labels.len(),
+            initial.len(),
+            "every label needs an initial state"
+        );
+        let mut states = initial.to_vec();
+
+        loop {
+            for (i, (label, on)) in labels.iter().zip(&states).enumerate() {
+                let mark = if *on { 'x' } else { ' ' };
+                self.write_line(&format!("{}. [{}] {}", i + 1, mark, label));
+            }
+
+            let flips: Vec<usize> = self.wait_parsed_msg(p, |s| {
+                s.split_whitespace()
+                    .map(|word| {
+                        let n: usize = word.parse().map_err(|_| None)?;
+                        n.checked_sub(1)
+                            .filter(|&i| i < labels.len())
+                            .ok_or_else(|| {
+                                Some(format!(
+                                    "Please enter a number within the bounds {:?}",
+                                    1..=labels.len()
+                                ))
+                            })
+                    })
+                    .collect()
+            });
+            if flips.is_empty() {
+                return states;
+            }
+            for i in flips {
+                states[i] = !states[i];
+            }
+        }
+    }
+
+    /// Presents `variants` on one numbered line and waits until the user picks one, either
+    /// by its number or by its name (its `Display` text, matched case-insensitively).
+    ///
+    /// Numbers are checked first, so a variant whose name is itself a number can only be
+    /// picked by its position. After that, a name matching exactly wins, and then the
+    /// start of a name is enough as long as only one variant begins with it. When
+    /// several do, they're listed and the user is asked again.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::fmt;
+    /// use std::io::Cursor;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum Color {
+    ///     Red,
+    ///     Green,
+    /// }
+    ///
+    /// impl fmt::Display for Color {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(f, "{:?}", self)
+    ///     }
+    /// }
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("2\nred\n")));
+    /// let variants = [Color::Red, Color::Green];
+    ///
+    /// assert_eq!(input.wait_enum_numbered(&variants, "Color: "), Color::Green);
+    /// assert_eq!(input.wait_enum_numbered(&variants, "Color: "), Color::Red);
+    ///
+    /// // "Red" is an exact match, even though "Redwood" starts with it too; "gr" can only
+    /// // be Green, but "re" is asked again, since it could be either of the other two
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("red\ngr\nre\nredw\n")));
+    /// let variants = ["Red", "Green", "Redwood"];
+    ///
+    /// assert_eq!(input.wait_enum_numbered(&variants, "Color: "), "Red");
+    /// assert_eq!(input.wait_enum_numbered(&variants, "Color: "), "Green");
+    /// assert_eq!(input.wait_enum_numbered(&variants, "Color: "), "Redwood");
+    /// ```
+    ///
+    /// The user in the first case would see the following:
+    /// ```plaintext
+    /// 1) Red  2) Green
+    /// Color:
+    /// ```
+    ///
+    /// And after entering `re` in the second:
+    /// ```plaintext
+    /// 're' could be any of: Red, Redwood
+    /// ```
+    pub fn wait_enum_numbered<T>(&self, variants: &[T], p: &str) -> T
+    where
+        T: std::fmt::Display + Clone,
+    {
+        let labels: Vec<String> = variants.iter().map(|v| v.to_string()).collect();
+        variants[self.pick_numbered(&labels, p)].clone()
+    }
+
+    /// Like [Input::wait_enum_numbered], but each option carries its own label, and the
+    /// label of the chosen option is returned alongside its value.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let sizes = [("Small (250ml)", 250), ("Large (500ml)", 500)];
+    /// let (ml, label) = Input::new()
+    ///     .chain_source(Box::new(Cursor::new("2\n")))
+    ///     .wait_labeled(&sizes, "Size: ");
+    ///
+    /// assert_eq!((ml, label.as_str()), (500, "Large (500ml)"));
+    /// ```
+    pub fn wait_labeled<T>(&self, options: &[(&str, T)], p: &str) -> (T, String)
+    where
+        T: Clone,
     {
-        self.get_data()
+        let labels: Vec<String> = options.iter().map(|(label, _)| label.to_string()).collect();
+        let index = self.pick_numbered(&labels, p);
+        (options[index].1.clone(), labels[index].clone())
     }
+
+    /// Shows `labels` on one numbered line and waits until the user picks one by number
+    /// or by label, returning its index
+    fn pick_numbered(&self, labels: &[String], p: &str) -> usize {
+        let menu: Vec<String> = labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| format!("{}) {}", i + 1, label))
+            .collect();
+        self.write_line(&menu.join("  "));
+
+        self.wait_parsed_msg(p, |s| {
+            if let Ok(n) = s.parse::<usize>() {
+                return match n.checked_sub(1).filter(|&i| i < labels.len()) {
+                    Some(i) => Ok(i),
+                    None => Err(Some(format!(
+                        "Please enter a number within the bounds {:?}",
+                        1..=labels.len()
+                    ))),
+                };
+            }
+
+            if let Some(i) = labels
+                .iter()
+                .position(|label| label.eq_ignore_ascii_case(s))
+            {
+                return Ok(i);
+            }
+            if s.is_empty() {
+                return Err(None);
+            }
+
+            let start = s.to_lowercase();
+            let matches: Vec<usize> = (0..labels.len())
+                .filter(|&i| labels[i].to_lowercase().starts_with(&start))
+                .collect();
+            match matches[..] {
+                [] => Err(None),
+                [i] => Ok(i),
+                _ => {
+                    let names: Vec<&str> = matches.iter().map(|&i| labels[i].as_str()).collect();
+                    Err(Some(format!(
+                        "'{}' could be any of: {}",
+                        s,
+                        names.join(", ")
+                    )))
+                }
+            }
+        })
+    }
+
+    /// Presents a simple "yes/no" option to the user, returning their choice
+    ///
+    /// This is useful for binary decisions, i.e. asking for confirmation before progressing
+    ///
+    /// `y`, `n`, `yes` and `no` are accepted in any case. Anything else is rejected with
+    /// `err_msg` (if set) before asking again, and the quit trigger works as it does for
+    /// `wait`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new()
+    ///     .err_msg("Please answer yes or no")
+    ///     .chain_source(Box::new(Cursor::new("maybe\nYes\n")));
+    ///
+    /// assert!(input.choose("Continue?"));
+    /// ```
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// if Input::new().choose("Continue?") {
+    ///     println!("You continued.");
+    /// }
+    /// ```
+    /// What the user would see:
+    /// ```plaintext
+    /// Continue? [y/n]
+    /// ```
+    pub fn choose(&self, p: &str) -> bool {
+        let (choice, last_input) = self.ask_yes_no(p);
+
+        self.print_success();
+        self.last_input.replace(last_input);
+        self.accepted_choice();
+        choice
+    }
+
+    /// Like [Input::choose], but with the answers spelled by `yes` and `no` (matched
+    /// regardless of case), and optionally a `default` that just pressing Enter picks.
+    ///
+    /// The prompt ends with the first `yes` and `no` characters in brackets, the
+    /// default's in upper case: `[Y/n]` for a default of yes. Anything else given is
+    /// rejected with `err_msg` (if set) before asking again.
+    ///
+    /// Panics if `yes` or `no` is empty.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let input = Input::new()
+    ///     .err_msg("Bitte j oder n eingeben")
+    ///     .with_reader(&b"y\n\nN\n"[..]);
+    ///
+    /// assert!(input.choose_opts("Fortfahren?", &['j'], &['n'], Some(true)));
+    /// assert!(!input.choose_opts("Fortfahren?", &['j'], &['n'], Some(true)));
+    /// ```
+    /// What the user sees:
+    /// ```plaintext
+    /// Fortfahren? [J/n] y
+    /// Bitte j oder n eingeben
+    /// Fortfahren? [J/n]
+    /// Fortfahren? [J/n] N
+    /// ```
+    pub fn choose_opts(&self, p: &str, yes: &[char], no: &[char], default: Option<bool>) -> bool {
+        assert!(
+            !yes.is_empty() && !no.is_empty(),
+            "choose_opts needs at least one character for each answer"
+        );
+
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.default_value = None;
+        ic.default_fn = None;
+        ic.sticky = false;
+        ic.sticky_value.take();
+        ic.memory = None;
+
+        let label = |chars: &[char], is_default: bool| match is_default {
+            true => chars[0].to_uppercase().to_string(),
+            false => chars[0].to_lowercase().to_string(),
+        };
+        let prompt = format!(
+            "{} [{}/{}] ",
+            p,
+            label(yes, default == Some(true)),
+            label(no, default == Some(false))
+        );
+        let is_in =
+            |chars: &[char], c: char| chars.iter().any(|a| a.to_lowercase().eq(c.to_lowercase()));
+
+        let choice = ic.wait_parsed(&prompt, |s| {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (None, _) => default,
+                (Some(c), None) if is_in(yes, c) => Some(true),
+                (Some(c), None) if is_in(no, c) => Some(false),
+                _ => None,
+            }
+        });
+
+        self.print_success();
+        self.last_input.replace(ic.last_input.take());
+        self.accepted_choice();
+        choice
+    }
+
+    /// Like [Input::choose], but gives up with [TooManyAttempts] after `max` responses
+    /// that aren't a yes or no, so a stuck or scripted input can't hang the program.
+    /// A `max` of 0 fails right away without asking.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, TooManyAttempts};
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("maybe\n?\nlater\ny\n")));
+    ///
+    /// assert_eq!(input.try_choose("Continue?", 3), Err(TooManyAttempts { attempts: 3 }));
+    /// assert_eq!(input.try_choose("Continue?", 3), Ok(true));
+    /// ```
+    pub fn try_choose(&self, p: &str, max: usize) -> Result<bool, TooManyAttempts> {
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.sticky = false;
+        ic.memory = None;
+        ic.prompt(&format!("{} [y/n] ", p));
+
+        let choice = ic.retry(max, |ic| yes_or_no(&ic.read::<String>()?))?;
+
+        self.print_success();
+        self.last_input.replace(ic.last_input.take());
+        self.accepted_choice();
+        Ok(choice)
+    }
+
+    /// Asks `p` with a `[y/n]` suffix until the user answers, without showing
+    /// `success_msg` or calling `on_accept`, returning the answer along with the input
+    /// it came from
+    fn ask_yes_no(&self, p: &str) -> (bool, (String, Option<String>)) {
+        let mut ic = self.clone();
+        ic.success_msg = None;
+        ic.on_accept = None;
+        ic.max_len = None;
+        ic.min_len = None;
+        ic.sticky = false;
+        ic.memory = None;
+
+        let choice = ic.wait_parsed(&format!("{} [y/n] ", p), yes_or_no);
+        (choice, ic.last_input.take())
+    }
+
+    /// Like [Input::choose], but after a "no" also asks why, returning the choice
+    /// together with the reason.
+    ///
+    /// The user can skip the reason by entering a blank line, giving `None`. The question
+    /// is set with [Input::reason_prompt], and [Input::reason_on_yes] asks after "yes"
+    /// instead.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "n\nToo expensive\ny\n";
+    /// let input = Input::new()
+    ///     .reason_prompt("Why not? ")
+    ///     .chain_source(Box::new(Cursor::new(lines)));
+    ///
+    /// assert_eq!(
+    ///     input.choose_with_reason("Approve?"),
+    ///     (false, Some("Too expensive".to_owned()))
+    /// );
+    /// assert_eq!(input.choose_with_reason("Approve?"), (true, None));
+    /// ```
+    pub fn choose_with_reason(&self, p: &str) -> (bool, Option<String>) {
+        let choice = self.choose(p);
+        if choice != self.reason_on_yes {
+            return (choice, None);
+        }
+
+        let prompt = self
+            .reason_prompt
+            .as_deref()
+            .unwrap_or("Reason (optional): ");
+        let reason: String = self.wait_parsed(prompt, |s| Some(s.to_owned()));
+        (choice, Some(reason).filter(|r| !r.is_empty()))
+    }
+
+    /// Like `choose`, but also offers a "quit" answer, returning `None` if it's chosen
+    ///
+    /// Entering `q` never ends the program, regardless of the `quit` trigger. The `quit`
+    /// trigger itself still works as usual: it ends the program, or with `soft_quit`
+    /// also returns `None`.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// match Input::new().choose_or_quit("Overwrite the file?") {
+    ///     Some(true) => println!("Overwritten."),
+    ///     Some(false) => println!("Skipped."),
+    ///     None => println!("Stopping here."),
+    /// }
+    /// ```
+    /// What the user would see:
+    /// ```plaintext
+    /// Overwrite the file? [y/n/q]
+    /// ```
+    ///
+    /// Anything else asks again:
+    /// ```
+    /// # use promptis::Input;
+    /// let input = Input::new().with_reader(&b"maybe\nY\nq\n"[..]);
+    ///
+    /// assert_eq!(input.choose_or_quit("Overwrite a.txt?"), Some(true));
+    /// assert_eq!(input.choose_or_quit("Overwrite b.txt?"), None);
+    /// ```
+    pub fn choose_or_quit(&self, p: &str) -> Option<bool> {
+        let prompt = format!("{} [y/n/q] ", p);
+        let mut attempts = 0;
+
+        loop {
+            let response =
+                self.get_raw_data(&prompt, |s| match s.trim().to_ascii_uppercase().as_str() {
+                    "Y" => Ok(Some(true)),
+                    "N" => Ok(Some(false)),
+                    "Q" => Ok(None),
+                    _ => Err(None),
+                });
+            self.check_error(&response);
+            attempts += 1;
+
+            match response {
+                Ok(choice) => {
+                    self.print_success();
+                    self.log_interaction(&prompt, attempts);
+                    if choice.is_some() {
+                        self.accepted_choice();
+                    }
+                    return choice;
+                }
+                Err(_) if self.quit_flag.get() => {
+                    self.log_interaction(&prompt, attempts);
+                    return None;
+                }
+                Err(_) => {}
+            }
+        }
+    }
+
+    /// Runs `f` until it returns `Some`, at most `max` times, returning its value or an
+    /// error once every attempt has failed.
+    ///
+    /// `f` is given this Input, so it can use any of the single-attempt methods like
+    /// `read`. A `max` of 0 fails right away without calling `f`.
+    ///
+    /// Example:
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let mut input = Input::new();
+    /// input.prompt("PIN: ");
+    ///
+    /// match input.retry(3, |i| i.read::<u32>()) {
+    ///     Ok(pin) => println!("Checking PIN {}", pin),
+    ///     Err(e) => println!("Locked out: {}", e),
+    /// }
+    /// ```
+    ///
+    /// It stops at the first success:
+    /// ```
+    /// # use promptis::{Input, TooManyAttempts};
+    /// let mut calls = 0;
+    /// let result = Input::new().retry(5, |_| {
+    ///     calls += 1;
+    ///     (calls == 3).then_some("third time lucky")
+    /// });
+    ///
+    /// assert_eq!(result, Ok("third time lucky"));
+    /// assert_eq!(calls, 3);
+    /// assert_eq!(Input::new().retry(2, |_| None::<()>), Err(TooManyAttempts { attempts: 2 }));
+    /// ```
+    pub fn retry<T, F>(&self, max: usize, mut f: F) -> Result<T, TooManyAttempts>
+    where
+        F: FnMut(&Self) -> Option<T>,
+    {
+        for _ in 0..max {
+            if let Some(value) = f(self) {
+                return Ok(value);
+            }
+        }
+
+        Err(TooManyAttempts { attempts: max })
+    }
+
+    /// Similar to `wait`, except will return after the user inputs anything.
+    ///
+    /// If the user input doesn't parse to `T`, `None` is returned.
+    ///
+    /// Example
+    /// ```no_run
+    /// # use promptis::Input;
+    /// let data: Option<i32> = Input::new()
+    ///     .prompt("Enter a number: ")
+    ///     .read();
+    ///
+    /// match data {
+    ///     Some(number) => println!("You entered {}", number),
+    ///     None => println!("You didn't enter a number")
+    /// }
+    /// ```
+    pub fn read<T>(&self) -> Option<T>
+    where
+        T: std::str::FromStr,
+    {
+        self.get_data(&self.user_prompt, |s| self.parse_valid(s).ok())
+    }
+
+    /// Like [Input::read], but also returns the input as the user typed it (trimmed, see
+    /// [Input::keep_whitespace]), e.g. to say what it was when it didn't parse.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new().with_reader(&b"  abc \n"[..]);
+    ///
+    /// let (age, typed) = input.prompt("Age: ").read_raw::<u32>();
+    /// if age.is_none() {
+    ///     println!("You typed '{}', which isn't a number", typed);
+    /// }
+    ///
+    /// assert_eq!((age, typed.as_str()), (None, "abc"));
+    /// ```
+    pub fn read_raw<T>(&self) -> (Option<T>, String)
+    where
+        T: std::str::FromStr,
+    {
+        let value = self.read();
+        let raw = self.strip(&self.last_input.borrow().0).to_owned();
+        (value, raw)
+    }
+
+    /// Like [Input::read], but the trimmed input is parsed by `parser` instead of
+    /// [FromStr](std::str::FromStr); see [Input::wait_with].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// let mut input = Input::new().with_reader(&b"on\nmaybe\n"[..]);
+    /// let switch = |s: &str| match s {
+    ///     "on" => Some(true),
+    ///     "off" => Some(false),
+    ///     _ => None,
+    /// };
+    /// input.prompt("Power: ");
+    ///
+    /// assert_eq!(input.read_with(switch), Some(true));
+    /// assert_eq!(input.read_with(switch), None);
+    /// ```
+    pub fn read_with<T, F>(&self, parser: F) -> Option<T>
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        self.get_data(&self.user_prompt, parser)
+    }
+
+    /// Like [Input::read], but says why there's no value: [InputError::Parse] for input
+    /// that couldn't be used, [InputError::Eof] or [InputError::Io] for no input at all,
+    /// and [InputError::Quit] for the quit trigger with [Input::soft_quit].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// let mut input = Input::new().with_reader(&b"12\ntwelve\n"[..]);
+    /// input.prompt("Number: ");
+    ///
+    /// assert!(matches!(input.try_read::<u32>(), Ok(12)));
+    /// assert!(matches!(
+    ///     input.try_read::<u32>(),
+    ///     Err(InputError::Parse { input }) if input == "twelve"
+    /// ));
+    /// assert!(matches!(input.try_read::<u32>(), Err(InputError::Eof)));
+    /// ```
+    ///
+    /// IO errors are returned rather than printed, down to the prompt failing to be
+    /// written because the output was closed:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// use std::io::{self, ErrorKind, Write};
+    ///
+    /// struct Closed;
+    ///
+    /// impl Write for Closed {
+    ///     fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+    ///         Err(ErrorKind::BrokenPipe.into())
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut input = Input::new().with_reader(&b"12\n"[..]).with_writer(Closed);
+    /// input.prompt("Number: ");
+    ///
+    /// assert!(matches!(
+    ///     input.try_read::<u32>(),
+    ///     Err(InputError::Io(e)) if e.kind() == ErrorKind::BrokenPipe
+    /// ));
+    /// // Nothing was read for the prompt that couldn't be shown
+    /// assert!(matches!(input.read_optional::<u32>(), Err(InputError::Io(_))));
+    ///
+    /// // `never_exit` keeps `wait` going, answering without the prompt
+    /// let mut input = Input::new()
+    ///     .never_exit(true)
+    ///     .with_reader(&b"12\n"[..])
+    ///     .with_writer(Closed);
+    /// assert_eq!(input.prompt("Number: ").wait::<u32>(), 12);
+    /// ```
+    pub fn try_read<T>(&self) -> Result<T, InputError>
+    where
+        T: std::str::FromStr,
+    {
+        let response = self
+            .fallibly(|| self.get_raw_data(&self.user_prompt, |s| self.parse_valid(self.strip(s))));
+        self.log_interaction(&self.user_prompt, 1);
+
+        match response {
+            Ok(value) => {
+                self.accepted();
+                Ok(value)
+            }
+            Err(_) => Err(self.failed_read()),
+        }
+    }
+
+    /// Says why the last single read produced no value
+    fn failed_read(&self) -> InputError {
+        self.read_failure().unwrap_or_else(|| {
+            if self.quit_flag.get() {
+                InputError::Quit
+            } else {
+                InputError::Parse {
+                    input: self.last_input.borrow().0.clone(),
+                }
+            }
+        })
+    }
+
+    /// Like [Input::try_read], but for optional values: an empty line gives `Ok(None)`,
+    /// so a deliberate skip can be told apart from input that couldn't be used, which
+    /// still gives [InputError::Parse].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, InputError};
+    /// let mut input = Input::new().with_reader(&b"42\n\nforty\n"[..]);
+    /// input.prompt("Age (optional): ");
+    ///
+    /// assert!(matches!(input.read_optional::<u8>(), Ok(Some(42))));
+    /// assert!(matches!(input.read_optional::<u8>(), Ok(None)));
+    /// assert!(matches!(
+    ///     input.read_optional::<u8>(),
+    ///     Err(InputError::Parse { input }) if input == "forty"
+    /// ));
+    /// ```
+    pub fn read_optional<T>(&self) -> Result<Option<T>, InputError>
+    where
+        T: std::str::FromStr,
+    {
+        let response = self.fallibly(|| {
+            self.get_data(&self.user_prompt, |s| match s.trim().is_empty() {
+                true => Some(None),
+                false => self.parse_valid(s).ok().map(Some),
+            })
+        });
+
+        response.ok_or_else(|| self.failed_read())
+    }
+
+    /// Displays `p` and reads a line like [Input::read], but on failure returns a message
+    /// ready to show the user, naming the field and quoting what was entered:
+    /// `invalid <field_name>: <input>`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::Input;
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("forty\n40\n")));
+    ///
+    /// assert_eq!(input.read_ctx::<u8>("Age: ", "age"), Err("invalid age: forty".to_owned()));
+    /// assert_eq!(input.read_ctx::<u8>("Age: ", "age"), Ok(40));
+    /// ```
+    pub fn read_ctx<T>(&self, p: &str, field_name: &str) -> Result<T, String>
+    where
+        T: std::str::FromStr,
+    {
+        self.get_data(p, |s| self.parse_valid(s).ok())
+            .ok_or_else(|| format!("invalid {}: {}", field_name, self.last_input.borrow().0))
+    }
+
+    /// Displays `p` and reads a line, returning it (trimmed) along with a guess at the
+    /// kind of value it holds, for REPLs that dispatch on what was typed.
+    ///
+    /// Kinds are tried in order, and the first that parses wins: [ValueKind::Int] (an
+    /// `i64`), then [ValueKind::Float] (a finite `f64`, so `inf` and `NaN` are strings),
+    /// then [ValueKind::Bool] (exactly `true` or `false`), and finally
+    /// [ValueKind::String].
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, ValueKind};
+    /// use std::io::Cursor;
+    ///
+    /// let input = Input::new().chain_source(Box::new(Cursor::new("42\n2.5\ntrue\nhi there\n")));
+    ///
+    /// assert_eq!(input.read_typed("> "), ("42".to_owned(), ValueKind::Int));
+    /// assert_eq!(input.read_typed("> "), ("2.5".to_owned(), ValueKind::Float));
+    /// assert_eq!(input.read_typed("> "), ("true".to_owned(), ValueKind::Bool));
+    /// assert_eq!(input.read_typed("> "), ("hi there".to_owned(), ValueKind::String));
+    /// ```
+    pub fn read_typed(&self, p: &str) -> (String, ValueKind) {
+        self.wait_parsed(p, |s| Some((s.to_owned(), ValueKind::infer(s))))
+    }
+
+    /// Displays `p` and reads lines until `step` has enough of them to make a value, for
+    /// input that may span several lines.
+    ///
+    /// Each line (without its line ending) is passed to `step`, which keeps whatever it
+    /// needs and returns [Step::More] to have another line read, or [Step::Done] with
+    /// the finished value. Only the first line is prompted for. The quit trigger is
+    /// checked on every line as usual; with `soft_quit`, a quitting line is skipped
+    /// rather than passed to `step`.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, Step};
+    /// use std::io::Cursor;
+    ///
+    /// let lines = "[1,\n [2, 3],\n 4]\n";
+    /// let input = Input::new().chain_source(Box::new(Cursor::new(lines)));
+    ///
+    /// // Keep reading until every bracket is closed
+    /// let mut text = String::new();
+    /// let mut depth = 0;
+    /// let list = input.wait_accumulate("List: ", |line| {
+    ///     text.push_str(line);
+    ///     for c in line.chars() {
+    ///         match c {
+    ///             '[' => depth += 1,
+    ///             ']' => depth -= 1,
+    ///             _ => {}
+    ///         }
+    ///     }
+    ///     if depth > 0 {
+    ///         Step::More
+    ///     } else {
+    ///         Step::Done(std::mem::take(&mut text))
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(list, "[1, [2, 3], 4]");
+    /// ```
+    pub fn wait_accumulate<T, F>(&self, p: &str, mut step: F) -> T
+    where
+        F: FnMut(&str) -> Step<T>,
+    {
+        let mut raw = String::new();
+        let mut prompt = Some(p);
+
+        loop {
+            let mut line = String::new();
+            if self.read_input(prompt, &mut line) {
+                continue;
+            }
+            prompt = None;
+            raw.push_str(&line);
+
+            if let Step::Done(value) = step(line.trim_end_matches(['\n', '\r'])) {
+                let raw = raw.trim_end_matches(['\n', '\r']);
+                self.note_input(raw, Some(raw));
+                self.print_success();
+                self.log_interaction(p, 1);
+                self.accepted();
+                return value;
+            }
+        }
+    }
+}
+
+/// Formats `opts` as numbered menu lines, the first numbered `offset + 1`
+/// Reads `s` as an answer to a yes/no question: `y`, `n`, `yes` or `no`, in any case
+fn yes_or_no(s: &str) -> Option<bool> {
+    match s.to_ascii_lowercase().as_str() {
+        "y" | "yes" => Some(true),
+        "n" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+fn render_numbered<T: fmt::Display>(opts: &[T], offset: usize) -> String {
+    opts.iter()
+        .enumerate()
+        .map(|(i, v)| format!("{}. {}\n", offset + i + 1, v))
+        .collect()
+}
+
+/// Shows `default` in brackets in the prompt `p`, ahead of any trailing `:`/`>` separator,
+/// e.g. `"Port: "` becomes `"Port [8080]: "`
+fn with_default(p: &str, default: &str) -> String {
+    with_note(p, &format!("[{}]", default))
+}
+
+/// Adds `note` to the prompt `p`, ahead of any trailing `:`/`>` separator
+fn with_note(p: &str, note: &str) -> String {
+    let head = p.trim_end();
+    let head = head
+        .strip_suffix([':', '>'])
+        .map(str::trim_end)
+        .unwrap_or(head);
+    let tail = &p[head.len()..];
+    let tail = if tail.is_empty() { " " } else { tail };
+    let gap = if head.is_empty() { "" } else { " " };
+
+    format!("{}{}{}{}", head, gap, note, tail)
+}
+
+/// Finds the candidate closest to `input` by edit distance, as long as it's close enough
+/// to plausibly be a typo: within `max` edits, or by default a third of its length (and
+/// at least one edit)
+fn closest<'a>(input: &str, candidates: &[&'a str], max: Option<usize>) -> Option<&'a str> {
+    let input = input.to_lowercase();
+
+    candidates
+        .iter()
+        .map(|c| (*c, levenshtein(&input, &c.to_lowercase())))
+        .filter(|(c, d)| *d <= max.unwrap_or((c.chars().count() / 3).max(1)))
+        .min_by_key(|(_, d)| *d)
+        .map(|(c, _)| c)
+}
+
+/// Like `closest`, but returns every candidate close enough to `input`
+fn close_to<'a>(input: &str, candidates: &[&'a str], max: Option<usize>) -> Vec<&'a str> {
+    let input = input.to_lowercase();
+
+    candidates
+        .iter()
+        .filter(|c| {
+            levenshtein(&input, &c.to_lowercase()) <= max.unwrap_or((c.chars().count() / 3).max(1))
+        })
+        .copied()
+        .collect()
+}
+
+/// Counts the single-character edits needed to turn `a` into `b`
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diag
+            } else {
+                1 + diag.min(above).min(row[j])
+            };
+            diag = above;
+        }
+    }
+
+    row[b.len()]
 }
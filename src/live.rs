@@ -0,0 +1,129 @@
+//! Reading a line key by key, with a running count of its length against a limit and
+//! the arrow keys recalling earlier lines
+
+use std::io::{stdin, Read};
+
+/// Reads a line typed at the terminal one key at a time. With a `max`, how many
+/// characters it has is shown after the cursor, e.g. `(12/20)`, and keys past the limit
+/// are refused. The up and down arrows step back and forth through `history` (oldest
+/// first), replacing what was typed, and down past the newest entry brings it back.
+/// Backspace deletes the last character, and other control keys and escape sequences are
+/// ignored. Returns the line with its line ending, or nothing at the end of input.
+///
+/// `show` writes to the terminal; the caller is expected to have turned off echo and line
+/// buffering.
+pub(crate) fn read_line(
+    max: Option<usize>,
+    history: &[String],
+    show: impl Fn(&str),
+) -> std::io::Result<String> {
+    let mut stdin = stdin().lock();
+    let mut line = String::new();
+    let mut count = 0;
+    // The bytes of a character still being typed
+    let mut partial = Vec::new();
+    // How many entries back from the newest the one shown is, and what was typed before
+    // the first was recalled
+    let mut recalled = 0;
+    let mut typed = String::new();
+
+    show(&counter(count, max));
+    loop {
+        let mut byte = [0];
+        if stdin.read(&mut byte)? == 0 {
+            show("\x1b[K");
+            return Ok(line);
+        }
+
+        match byte[0] {
+            b'\r' | b'\n' => {
+                show("\x1b[K\n");
+                line.push('\n');
+                return Ok(line);
+            }
+            // Ctrl-D ends input only when nothing has been typed, as it does normally
+            0x04 if line.is_empty() => {
+                show("\x1b[K");
+                return Ok(line);
+            }
+            0x7f | 0x08 => {
+                if line.pop().is_some() {
+                    count -= 1;
+                    show(&format!("\x1b[D{}", counter(count, max)));
+                }
+            }
+            0x1b => {
+                let shown = match read_escape(&mut stdin)? {
+                    Some(b'A') if recalled < history.len() => {
+                        if recalled == 0 {
+                            typed = std::mem::take(&mut line);
+                        }
+                        recalled += 1;
+                        &history[history.len() - recalled]
+                    }
+                    Some(b'B') if recalled > 0 => {
+                        recalled -= 1;
+                        match recalled {
+                            0 => &typed,
+                            n => &history[history.len() - n],
+                        }
+                    }
+                    _ => continue,
+                };
+                let shown: String = shown.chars().take(max.unwrap_or(usize::MAX)).collect();
+                // Back to where the line started, then over what was there
+                let back = match count {
+                    0 => String::new(),
+                    n => format!("\x1b[{}D", n),
+                };
+                count = shown.chars().count();
+                show(&format!("{}{}{}", back, shown, counter(count, max)));
+                line = shown;
+            }
+            b if b < 0x20 => {}
+            b => {
+                partial.push(b);
+                match std::str::from_utf8(&partial) {
+                    Ok(c) if max.is_none_or(|max| count < max) => {
+                        line.push_str(c);
+                        count += 1;
+                        show(&format!("{}{}", c, counter(count, max)));
+                    }
+                    // A bell for a key past the limit
+                    Ok(_) => show("\x07"),
+                    Err(e) if e.error_len().is_none() => continue,
+                    Err(_) => {}
+                }
+                partial.clear();
+            }
+        }
+    }
+}
+
+/// Clears the rest of the line and, with a `max`, writes the count there, leaving the
+/// cursor where it was
+fn counter(count: usize, max: Option<usize>) -> String {
+    match max {
+        Some(max) => {
+            let count = format!(" ({}/{})", count, max);
+            format!("\x1b[K{}\x1b[{}D", count, count.len())
+        }
+        None => "\x1b[K".to_owned(),
+    }
+}
+
+/// Reads the rest of an escape sequence whose `ESC` was just read, such as the `[A` of
+/// the up arrow, returning its final byte (the `A`)
+fn read_escape(stdin: &mut impl Read) -> std::io::Result<Option<u8>> {
+    let mut byte = [0];
+    if stdin.read(&mut byte)? == 0 || !matches!(byte[0], b'[' | b'O') {
+        return Ok(None);
+    }
+    // Parameters, up to the final byte
+    while stdin.read(&mut byte)? > 0 {
+        if (0x40..=0x7e).contains(&byte[0]) {
+            return Ok(Some(byte[0]));
+        }
+    }
+    Ok(None)
+}
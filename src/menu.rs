@@ -0,0 +1,105 @@
+use std::fmt;
+use std::rc::Rc;
+
+/// A closure writing an option's menu line from its index and text
+type LabelFn = dyn Fn(usize, &str) -> String;
+
+/// How [Input::wait_opts](crate::Input::wait_opts) labels its options, and so what the
+/// user types to pick one, see [Input::option_style](crate::Input::option_style)
+#[derive(Clone, Default)]
+pub enum OptionStyle {
+    /// `1. First`, `2. Second`, ...
+    #[default]
+    NumberedFromOne,
+    /// `0. First`, `1. Second`, ...
+    NumberedFromZero,
+    /// `a) First`, `b) Second`, ... up to `z)`, then `aa)`, `ab)` and so on. Either case
+    /// picks an option.
+    Lettered,
+    /// Each line is whatever the closure makes of the option's zero-based index and its
+    /// text. Options are still picked by number from one.
+    Custom(Rc<LabelFn>),
+}
+
+impl fmt::Debug for OptionStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionStyle::NumberedFromOne => f.write_str("NumberedFromOne"),
+            OptionStyle::NumberedFromZero => f.write_str("NumberedFromZero"),
+            OptionStyle::Lettered => f.write_str("Lettered"),
+            OptionStyle::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl OptionStyle {
+    /// The menu line for the option at `index` (without a line break)
+    pub(crate) fn render(&self, index: usize, text: &str) -> String {
+        match self {
+            OptionStyle::NumberedFromOne => format!("{}. {}", index + 1, text),
+            OptionStyle::NumberedFromZero => format!("{}. {}", index, text),
+            OptionStyle::Lettered => format!("{}) {}", letters(index), text),
+            OptionStyle::Custom(f) => f(index, text),
+        }
+    }
+
+    /// The index of the option among `len` that `answer` picks by its label, if any
+    pub(crate) fn pick(&self, answer: &str, len: usize) -> Option<usize> {
+        let index = match self {
+            OptionStyle::NumberedFromZero => answer.parse().ok(),
+            OptionStyle::Lettered => from_letters(answer),
+            OptionStyle::NumberedFromOne | OptionStyle::Custom(_) => {
+                answer.parse::<usize>().ok().and_then(|n| n.checked_sub(1))
+            }
+        };
+        index.filter(|&i| i < len)
+    }
+
+    /// Tells the user which labels pick one of `len` options
+    pub(crate) fn bounds_hint(&self, len: usize) -> String {
+        match self {
+            OptionStyle::NumberedFromZero => {
+                format!("Please enter a number within the bounds {:?}", 0..=len - 1)
+            }
+            OptionStyle::Lettered => format!(
+                "Please enter a letter from {} to {}",
+                letters(0),
+                letters(len - 1)
+            ),
+            OptionStyle::NumberedFromOne | OptionStyle::Custom(_) => {
+                format!("Please enter a number within the bounds {:?}", 1..=len)
+            }
+        }
+    }
+}
+
+/// The letters labeling the option at `index`: `a` to `z`, then `aa`, `ab`, ...
+fn letters(index: usize) -> String {
+    let mut label = Vec::new();
+    let mut n = index + 1;
+    while n > 0 {
+        n -= 1;
+        label.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    label.reverse();
+    String::from_utf8(label).unwrap_or_default()
+}
+
+/// The index labeled by `letters`, ignoring case
+fn from_letters(letters: &str) -> Option<usize> {
+    if letters.is_empty() {
+        return None;
+    }
+    letters
+        .bytes()
+        .try_fold(0usize, |n, b| {
+            let digit = match b {
+                b'a'..=b'z' => b - b'a',
+                b'A'..=b'Z' => b - b'A',
+                _ => return None,
+            };
+            n.checked_mul(26)?.checked_add(digit as usize + 1)
+        })?
+        .checked_sub(1)
+}
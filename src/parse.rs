@@ -0,0 +1,358 @@
+//! Helpers for turning raw input lines into structured values
+
+use std::fmt;
+use std::time::Duration;
+
+/// Slices `line` into columns of the given character `widths`, or `None` if the
+/// line doesn't reach the last column
+pub(crate) fn split_columns(line: &str, widths: &[usize]) -> Option<Vec<String>> {
+    let chars: Vec<char> = line.chars().collect();
+    let needed: usize = widths.iter().take(widths.len().saturating_sub(1)).sum();
+
+    if !widths.is_empty() && chars.len() <= needed {
+        return None;
+    }
+
+    let mut cols = Vec::with_capacity(widths.len() + 1);
+    let mut start = 0;
+
+    for w in widths {
+        let end = (start + w).min(chars.len());
+        cols.push(
+            chars[start..end]
+                .iter()
+                .collect::<String>()
+                .trim()
+                .to_owned(),
+        );
+        start = end;
+    }
+
+    let rest: String = chars[start..].iter().collect();
+    if !rest.trim().is_empty() {
+        cols.push(rest.trim().to_owned());
+    }
+
+    Some(cols)
+}
+
+/// Splits `line` on whitespace, keeping quoted sections together, and returns each
+/// word alongside whether it was quoted; `None` if a quote is left unclosed
+pub(crate) fn split_words(line: &str) -> Option<Vec<(String, bool)>> {
+    let mut words = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut word = String::new();
+        let mut quoted = false;
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+            if c == '"' || c == '\'' {
+                quoted = true;
+                loop {
+                    match chars.next() {
+                        Some(q) if q == c => break,
+                        Some(q) => word.push(q),
+                        None => return None,
+                    }
+                }
+            } else {
+                word.push(c);
+            }
+        }
+        words.push((word, quoted));
+    }
+
+    Some(words)
+}
+
+/// Removes ANSI escape sequences and control characters other than tabs and line endings
+pub(crate) fn sanitize(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // A CSI sequence runs until its final byte in '@'..='~'
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // An OSC sequence runs until BEL or ST (ESC \)
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\t' | '\n' | '\r' => out.push(c),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Parses a duration made of whole numbers with units, e.g. `90s`, `500ms` or `1h30m`
+pub(crate) fn duration(s: &str) -> Option<Duration> {
+    const UNITS: [(&str, u64); 5] = [
+        ("ms", 1),
+        ("s", 1_000),
+        ("m", 60_000),
+        ("h", 3_600_000),
+        ("d", 86_400_000),
+    ];
+
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut millis: u64 = 0;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let n: u64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+
+        // "ms" has to be tried before "m"
+        let (unit, scale) = UNITS.iter().find(|(unit, _)| rest.starts_with(unit))?;
+        rest = &rest[unit.len()..];
+        millis = millis.checked_add(n.checked_mul(*scale)?)?;
+    }
+
+    Some(Duration::from_millis(millis))
+}
+
+/// Parses a number in scientific notation, accepting `1e3`, `1E3`, `1x10^3`, `1*10^3`,
+/// `1×10^3` and `10^3`, with any spaces, as well as plain numbers. Infinities and `NaN`
+/// are rejected.
+pub(crate) fn scientific(s: &str) -> Option<f64> {
+    let s: String = s
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| {
+            if c == '×' {
+                'x'
+            } else {
+                c.to_ascii_lowercase()
+            }
+        })
+        .collect();
+
+    let s = match s.split_once("10^") {
+        Some(("", exponent)) => format!("1e{}", exponent),
+        Some((mantissa, exponent)) => {
+            format!("{}e{}", mantissa.strip_suffix(['x', '*'])?, exponent)
+        }
+        None => s,
+    };
+
+    // Rules out words f64 would otherwise take, like "inf"
+    if !s
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e'))
+    {
+        return None;
+    }
+    s.parse().ok().filter(|n: &f64| n.is_finite())
+}
+
+/// Checks that `s` is shaped like an email address: one `@` between a non-empty local
+/// part and a domain containing a dot (not at either end), with no whitespace
+pub(crate) fn is_email(s: &str) -> bool {
+    let Some((local, domain)) = s.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !s.contains(char::is_whitespace)
+}
+
+/// An amount of money as entered, e.g. `-$1,234.56`, split into exact decimal digits
+pub(crate) struct Amount {
+    negative: bool,
+    whole: String,
+    frac: String,
+}
+
+impl Amount {
+    /// Parses `s`, allowing a `symbol` before or after the number, a sign before or
+    /// after everything, and `,` thousands separators (which must group by threes)
+    pub(crate) fn parse(s: &str, symbol: &str) -> Option<Self> {
+        let mut s = s.trim();
+        let mut negative = false;
+
+        if let Some(rest) = s.strip_prefix('-') {
+            (negative, s) = (true, rest.trim_start());
+        } else if let Some(rest) = s.strip_suffix('-') {
+            (negative, s) = (true, rest.trim_end());
+        } else if let Some(rest) = s.strip_prefix('+') {
+            s = rest.trim_start();
+        }
+
+        if !symbol.is_empty() {
+            if let Some(rest) = s.strip_prefix(symbol) {
+                s = rest.trim_start();
+            } else if let Some(rest) = s.strip_suffix(symbol) {
+                s = rest.trim_end();
+            }
+        }
+
+        // Allow the sign between the symbol and the digits too, as in "$-5"
+        if !negative {
+            if let Some(rest) = s.strip_prefix('-') {
+                (negative, s) = (true, rest);
+            }
+        }
+
+        let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+        let groups: Vec<&str> = whole.split(',').collect();
+        let grouped_ok = groups.len() == 1
+            || (!groups[0].is_empty()
+                && groups[0].len() <= 3
+                && groups[1..].iter().all(|g| g.len() == 3));
+        let whole: String = groups.concat();
+
+        let digits = |d: &str| d.chars().all(|c| c.is_ascii_digit());
+        if !grouped_ok || !digits(&whole) || !digits(frac) || (whole.is_empty() && frac.is_empty())
+        {
+            return None;
+        }
+
+        Some(Self {
+            negative,
+            whole,
+            frac: frac.to_owned(),
+        })
+    }
+
+    /// Converts to the nearest `f64`
+    pub(crate) fn to_f64(&self) -> f64 {
+        let sign = if self.negative { "-" } else { "" };
+        let whole = if self.whole.is_empty() {
+            "0"
+        } else {
+            &self.whole
+        };
+        format!("{}{}.{}0", sign, whole, self.frac)
+            .parse()
+            .unwrap_or(0.0)
+    }
+
+    /// Converts to an exact number of cents, or `None` if there are fractions of a
+    /// cent or it doesn't fit
+    pub(crate) fn to_cents(&self) -> Option<i64> {
+        if self.frac.len() > 2 {
+            return None;
+        }
+        let whole: i64 = if self.whole.is_empty() {
+            0
+        } else {
+            self.whole.parse().ok()?
+        };
+        let frac: i64 = format!("{:0<2}", self.frac).parse().ok()?;
+        let cents = whole.checked_mul(100)?.checked_add(frac)?;
+        Some(if self.negative { -cents } else { cents })
+    }
+}
+
+/// The kind of value a line of input looks like, as guessed by
+/// [Input::read_typed](crate::Input::read_typed)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValueKind {
+    /// A whole number that fits in an `i64`, e.g. `-42`
+    Int,
+    /// A finite number that isn't a valid `Int`, e.g. `2.5` or `1e3`
+    Float,
+    /// `true` or `false`
+    Bool,
+    /// Anything else
+    String,
+}
+
+/// What [Input::wait_accumulate](crate::Input::wait_accumulate)'s closure makes of the
+/// lines it's been given so far
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step<T> {
+    /// The value isn't complete yet, so another line should be read
+    More,
+    /// The value is complete
+    Done(T),
+}
+
+impl ValueKind {
+    /// Guesses the kind of `s`, trying each kind in declaration order
+    pub(crate) fn infer(s: &str) -> Self {
+        if s.parse::<i64>().is_ok() {
+            ValueKind::Int
+        } else if s.parse::<f64>().is_ok_and(f64::is_finite) {
+            ValueKind::Float
+        } else if s.parse::<bool>().is_ok() {
+            ValueKind::Bool
+        } else {
+            ValueKind::String
+        }
+    }
+}
+
+/// Inclusive bounds on a parsed value, as set by [Input::min](crate::Input::min) and
+/// [Input::max](crate::Input::max)
+#[derive(Clone)]
+pub(crate) struct Bounds<T> {
+    pub(crate) min: Option<T>,
+    pub(crate) max: Option<T>,
+    check: fn(&Self, &T) -> Option<String>,
+}
+
+impl<T: PartialOrd + fmt::Display> Bounds<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            check: Self::out_of_range,
+        }
+    }
+
+    fn out_of_range(&self, value: &T) -> Option<String> {
+        let below = self.min.as_ref().is_some_and(|min| value < min);
+        let above = self.max.as_ref().is_some_and(|max| value > max);
+
+        match (&self.min, &self.max) {
+            (Some(min), Some(max)) if below || above => {
+                Some(format!("{} must be between {} and {}", value, min, max))
+            }
+            (Some(min), _) if below => Some(format!("{} must be at least {}", value, min)),
+            (_, Some(max)) if above => Some(format!("{} must be at most {}", value, max)),
+            _ => None,
+        }
+    }
+}
+
+impl<T> Bounds<T> {
+    /// Describes how `value` falls outside the bounds, if it does
+    pub(crate) fn check(&self, value: &T) -> Option<String> {
+        (self.check)(self, value)
+    }
+}
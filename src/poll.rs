@@ -0,0 +1,170 @@
+//! Reading stdin on a background thread, so input can be polled without blocking.
+//!
+//! The thread is only started the first time input is polled. From then on every
+//! read goes through it, so polling and blocking reads never race over stdin.
+
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::io::stdin;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::mpsc::{self, Receiver, RecvError, RecvTimeoutError};
+use std::sync::{Mutex, MutexGuard, OnceLock, TryLockError};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+static LINES: OnceLock<Mutex<Receiver<String>>> = OnceLock::new();
+
+/// A line already taken from the background reader by `LineReady`, for the next read
+static PEEKED: Mutex<Option<String>> = Mutex::new(None);
+
+/// Gets the background reader's lines, starting it if it isn't running yet
+fn lines() -> &'static Mutex<Receiver<String>> {
+    LINES.get_or_init(|| {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || loop {
+            let mut buffer = String::new();
+            match stdin().read_line(&mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    if tx.send(buffer).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        Mutex::new(rx)
+    })
+}
+
+/// Locks the background reader's lines, starting it if it isn't running yet
+fn lock_lines() -> MutexGuard<'static, Receiver<String>> {
+    lines().lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Whether the background reader has started, after which stdin is only read through it
+pub(crate) fn started() -> bool {
+    LINES.get().is_some()
+}
+
+/// Takes the line `LineReady` read ahead, if there is one. Every read checks this once
+/// it holds the lines, so a line read ahead is always used first.
+fn take_peeked() -> Option<String> {
+    PEEKED.lock().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// Returns the next complete line if one has already been read, without blocking
+pub(crate) fn try_line() -> Option<String> {
+    if let Some(line) = take_peeked() {
+        return Some(line);
+    }
+    let rx = match lines().try_lock() {
+        Ok(rx) => rx,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        // Someone else is waiting for the next line, so there isn't one yet
+        Err(TryLockError::WouldBlock) => return None,
+    };
+    take_peeked().or_else(|| rx.try_recv().ok())
+}
+
+/// Reads a line from stdin into `buf`, taking it from the background reader if one is running
+pub(crate) fn read_line(buf: &mut String) -> std::io::Result<usize> {
+    let Some(lines) = LINES.get() else {
+        return stdin().read_line(buf);
+    };
+
+    let rx = lines.lock().unwrap_or_else(|e| e.into_inner());
+    match take_peeked().ok_or(RecvError).or_else(|_| rx.recv()) {
+        Ok(line) => {
+            buf.push_str(&line);
+            Ok(line.len())
+        }
+        Err(RecvError) => Ok(0),
+    }
+}
+
+/// Like `read_line`, but waits at most `timeout` for the line (starting the background
+/// reader if needed), returning `None` if none arrived in time
+pub(crate) fn read_line_timeout(buf: &mut String, timeout: Duration) -> Option<usize> {
+    let rx = lock_lines();
+    let line = take_peeked().ok_or(RecvTimeoutError::Timeout);
+    match line.or_else(|_| rx.recv_timeout(timeout)) {
+        Ok(line) => {
+            buf.push_str(&line);
+            Some(line.len())
+        }
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => Some(0),
+    }
+}
+
+/// Whether a thread is waiting for a line to set aside for `LineReady`, and who to wake
+/// when it has one
+#[cfg(feature = "async")]
+struct Ahead {
+    reading: bool,
+    ended: bool,
+    wakers: Vec<Waker>,
+}
+
+#[cfg(feature = "async")]
+static AHEAD: Mutex<Ahead> = Mutex::new(Ahead {
+    reading: false,
+    ended: false,
+    wakers: Vec::new(),
+});
+
+/// A future that's ready once a line of stdin can be read without blocking (or stdin has
+/// ended), so async callers can wait for the user without holding up their thread.
+///
+/// A background thread waits for the line and sets it aside for the next read. If the
+/// future is dropped before then, the line is still set aside when it arrives.
+#[cfg(feature = "async")]
+pub(crate) struct LineReady;
+
+#[cfg(feature = "async")]
+impl Future for LineReady {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut ahead = AHEAD.lock().unwrap_or_else(|e| e.into_inner());
+        let peeked = PEEKED.lock().unwrap_or_else(|e| e.into_inner()).is_some();
+        if peeked || ahead.ended {
+            return Poll::Ready(());
+        }
+
+        if !ahead.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            ahead.wakers.push(cx.waker().clone());
+        }
+        if !ahead.reading {
+            ahead.reading = true;
+            std::thread::spawn(read_ahead);
+        }
+        Poll::Pending
+    }
+}
+
+/// Waits for the next line and sets it aside, then wakes everyone waiting on `LineReady`
+#[cfg(feature = "async")]
+fn read_ahead() {
+    let ended = {
+        let rx = lock_lines();
+        let mut peeked = PEEKED.lock().unwrap_or_else(|e| e.into_inner());
+        if peeked.is_none() {
+            // Not holding `peeked` while blocked, or reads elsewhere would wait on it
+            drop(peeked);
+            let line = rx.recv();
+            peeked = PEEKED.lock().unwrap_or_else(|e| e.into_inner());
+            *peeked = line.ok();
+        }
+        peeked.is_none()
+    };
+
+    let mut ahead = AHEAD.lock().unwrap_or_else(|e| e.into_inner());
+    ahead.reading = false;
+    ahead.ended = ended;
+    for waker in ahead.wakers.drain(..) {
+        waker.wake();
+    }
+}
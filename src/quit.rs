@@ -0,0 +1,25 @@
+/// How input is compared with the `quit` triggers, see
+/// [Input::quit_match](crate::Input::quit_match). The input is trimmed first either way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuitMatch {
+    /// The input must be the trigger exactly
+    #[default]
+    Exact,
+    /// The input must start with the trigger, so `quit now` matches `quit`
+    Prefix,
+    /// The input must contain the trigger somewhere, so `save :q` matches `:q`
+    Contains,
+}
+
+impl QuitMatch {
+    /// Whether `input` matches `trigger` this way. An empty trigger only matches empty
+    /// input, however it's matched.
+    pub(crate) fn matches(self, trigger: &str, input: &str) -> bool {
+        match self {
+            _ if trigger.is_empty() => input.is_empty(),
+            QuitMatch::Exact => input == trigger,
+            QuitMatch::Prefix => input.starts_with(trigger),
+            QuitMatch::Contains => input.contains(trigger),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Semantic version parsing for [Input::wait_semver](crate::Input::wait_semver),
+//! enabled by the `semver` feature.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A semantic version, as described at <https://semver.org>
+///
+/// Versions are ordered by precedence: major, minor and patch numerically, then a
+/// version with pre-release identifiers comes before the same version without them.
+/// Build metadata doesn't affect precedence, but is compared last so that ordering
+/// agrees with equality.
+///
+/// Example:
+/// ```
+/// # use promptis::Version;
+/// let v: Version = "1.4.2-beta.1+build.7".parse().unwrap();
+///
+/// assert_eq!((v.major, v.minor, v.patch), (1, 4, 2));
+/// assert_eq!(v.pre, ["beta", "1"]);
+/// assert_eq!(v.build, ["build", "7"]);
+/// assert!(v < "1.4.2".parse().unwrap());
+/// assert!("1.4".parse::<Version>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// Pre-release identifiers, e.g. `["rc", "1"]` for `1.0.0-rc.1`
+    pub pre: Vec<String>,
+    /// Build metadata identifiers, e.g. `["sha", "5114f85"]` for `1.0.0+sha.5114f85`
+    pub build: Vec<String>,
+}
+
+/// Why a string isn't a valid [Version]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionError(&'static str);
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for VersionError {}
+
+impl FromStr for Version {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rest, build) = match s.split_once('+') {
+            Some((rest, build)) => (rest, identifiers(build, false)?),
+            None => (s, Vec::new()),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, identifiers(pre, true)?),
+            None => (rest, Vec::new()),
+        };
+
+        let parts: Vec<&str> = core.split('.').collect();
+        let [major, minor, patch] = parts[..] else {
+            return Err(VersionError("expected MAJOR.MINOR.PATCH"));
+        };
+
+        Ok(Self {
+            major: number(major)?,
+            minor: number(minor)?,
+            patch: number(patch)?,
+            pre,
+            build,
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre.join("."))?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => cmp_identifiers(&self.pre, &other.pre),
+            })
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parses a numeric version part, which can't have leading zeros
+fn number(s: &str) -> Result<u64, VersionError> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return Err(VersionError("version numbers must be digits"));
+    }
+    if s.len() > 1 && s.starts_with('0') {
+        return Err(VersionError("version numbers can't have leading zeros"));
+    }
+    s.parse()
+        .map_err(|_| VersionError("version number is too large"))
+}
+
+/// Parses dot-separated pre-release or build identifiers
+fn identifiers(s: &str, pre: bool) -> Result<Vec<String>, VersionError> {
+    s.split('.')
+        .map(|id| {
+            if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                Err(VersionError("identifiers must be non-empty [0-9A-Za-z-]"))
+            } else if pre
+                && id.len() > 1
+                && id.starts_with('0')
+                && id.chars().all(|c| c.is_ascii_digit())
+            {
+                Err(VersionError("numeric identifiers can't have leading zeros"))
+            } else {
+                Ok(id.to_owned())
+            }
+        })
+        .collect()
+}
+
+/// Compares pre-release identifiers: numeric ones numerically and before
+/// alphanumeric ones, which compare as text
+fn cmp_identifiers(a: &[String], b: &[String]) -> Ordering {
+    for (x, y) in a.iter().zip(b) {
+        let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => x.cmp(y),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
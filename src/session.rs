@@ -0,0 +1,178 @@
+//! Multi-step prompting that either finishes completely or not at all.
+
+use std::rc::Rc;
+
+use crate::{Callback, Input, SessionAborted};
+
+/// Checks an answer to one question the way `wait` would, saying why it's rejected
+type AcceptFn = dyn Fn(&str) -> Result<(), Option<String>>;
+
+/// A series of questions asked one after another with the same [Input], whose
+/// answers are only handed back once every question has been answered.
+///
+/// Entering the quit trigger at any step aborts the whole session, without ending the
+/// program, as does the input running out. None of the answers given so far are kept.
+///
+/// Example:
+/// ```
+/// # use promptis::{Input, PromptSession, SessionAborted};
+/// use std::io::Cursor;
+///
+/// let input = Input::new()
+///     .quit("quit")
+///     .chain_source(Box::new(Cursor::new("Ada\nthirty\n36\nAda\nquit\n")));
+/// let session = PromptSession::new(input)
+///     .ask::<String>("name", "Name: ")
+///     .ask::<u32>("age", "Age: ");
+///
+/// let answers = session.run().unwrap();
+/// assert_eq!(answers.get("name"), Some("Ada"));
+/// assert_eq!(answers.parse::<u32>("age"), Some(36));
+///
+/// // Quitting at the second question aborts everything
+/// assert_eq!(session.run(), Err(SessionAborted));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PromptSession {
+    input: Input,
+    steps: Vec<Step>,
+}
+
+/// One question of a [PromptSession]
+#[derive(Debug, Clone)]
+struct Step {
+    key: String,
+    prompt: String,
+    accepts: Callback<AcceptFn>,
+}
+
+impl PromptSession {
+    /// Creates a session that asks its questions with `input`.
+    pub fn new(input: Input) -> Self {
+        Self {
+            input,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a question, shown as `prompt`, whose answer must parse to `T` and is stored
+    /// under `key`. The answer is checked just as `wait` would check it, including the
+    /// Input's `validate` predicate and `min`/`max` bounds for `T`, with the same error
+    /// messages.
+    pub fn ask<T: std::str::FromStr + 'static>(mut self, key: &str, prompt: &str) -> Self {
+        let input = self.input.clone();
+        self.steps.push(Step {
+            key: key.to_owned(),
+            prompt: prompt.to_owned(),
+            accepts: Callback(Rc::new(move |s: &str| {
+                input.parse_valid::<T>(s).map(|_| ())
+            })),
+        });
+        self
+    }
+
+    /// Asks every question in order, returning all the answers, or [SessionAborted] if
+    /// the quit trigger was entered at any point, or the input ended or couldn't be read
+    /// (or `max_attempts` ran out) before the last answer.
+    ///
+    /// Nothing an answer would otherwise set off happens until the last question has been
+    /// answered: `on_accept` hooks, `remember` and `sticky_default`, `record_history`,
+    /// `echo` and the `json_transcript` all wait until then, so an aborted session leaves
+    /// nothing behind.
+    ///
+    /// Example:
+    /// ```
+    /// # use promptis::{Input, PromptSession, SessionAborted};
+    /// use std::cell::RefCell;
+    /// use std::io::Cursor;
+    /// use std::rc::Rc;
+    ///
+    /// let seen = Rc::new(RefCell::new(Vec::new()));
+    /// let log = Rc::clone(&seen);
+    /// let input = Input::new()
+    ///     .quit("quit")
+    ///     .min(1u8)
+    ///     .on_accept(move |line| log.borrow_mut().push(line.to_owned()))
+    ///     .chain_source(Box::new(Cursor::new("Ada\n0\nquit\nAda\n7\nBob\n")));
+    /// let session = PromptSession::new(input)
+    ///     .ask::<String>("name", "Name: ")
+    ///     .ask::<u8>("level", "Level: ");
+    ///
+    /// // "0" is below the Input's `min`, then "quit" aborts with nothing accepted yet
+    /// assert_eq!(session.run(), Err(SessionAborted));
+    /// assert!(seen.borrow().is_empty());
+    ///
+    /// let answers = session.run().unwrap();
+    /// assert_eq!(answers.parse::<u8>("level"), Some(7));
+    /// assert_eq!(*seen.borrow(), ["Ada", "7"]);
+    ///
+    /// // The input runs out after "Bob", which aborts too
+    /// assert_eq!(session.run(), Err(SessionAborted));
+    /// assert_eq!(seen.borrow().len(), 2);
+    /// ```
+    pub fn run(&self) -> Result<Answers, SessionAborted> {
+        let mut input = self.input.clone();
+        input.on_accept = None;
+        input.memory = None;
+        input.record_history = false;
+        input.echo_answers = false;
+        input.hold_quit.set(true);
+        #[cfg(feature = "json")]
+        let transcript = Rc::new(std::cell::RefCell::new(Vec::new()));
+        #[cfg(feature = "json")]
+        if input.transcript.is_some() {
+            input.transcript = Some(crate::Sink(transcript.clone()));
+        }
+
+        let mut answers = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let answer = input.fallibly(|| {
+                input.wait_loop(
+                    &step.prompt,
+                    |s| {
+                        let s = s.trim();
+                        (step.accepts.0)(s).map(|_| s.to_owned())
+                    },
+                    true,
+                )
+            });
+            match answer {
+                Ok(answer) => answers.push((step.key.clone(), answer)),
+                Err(_) => return Err(SessionAborted),
+            }
+        }
+
+        // Everything's answered, so let the answers have their effects after all
+        for (step, (_, answer)) in self.steps.iter().zip(&answers) {
+            self.input.remember_default(answer);
+            self.input.echo_answer(&step.prompt, answer);
+            self.input.note_input(answer, Some(answer));
+            self.input.accepted();
+        }
+        #[cfg(feature = "json")]
+        if let Some(sink) = &self.input.transcript {
+            let mut w = sink.0.borrow_mut();
+            let _ = w.write_all(&transcript.borrow()).and_then(|_| w.flush());
+        }
+        Ok(Answers(answers))
+    }
+}
+
+/// The answers from a finished [PromptSession], by key
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(Vec<(String, String)>);
+
+impl Answers {
+    /// Returns the answer stored under `key`, as it was entered (trimmed).
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, answer)| answer.as_str())
+    }
+
+    /// Returns the answer stored under `key`, parsed to `T`.
+    pub fn parse<T: std::str::FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key)?.parse().ok()
+    }
+}
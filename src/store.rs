@@ -0,0 +1,42 @@
+//! Answers saved between runs by [Input::remember](crate::Input::remember), kept as
+//! `key=value` lines in a plain text file.
+
+use std::io;
+use std::path::Path;
+
+/// Looks up the value saved under `key`, if the file exists and has one
+pub(crate) fn load(path: &Path, key: &str) -> Option<String> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    contents
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| v.to_owned())
+}
+
+/// Saves `value` under `key`, keeping every other line of the file as it was
+pub(crate) fn save(path: &Path, key: &str, value: &str) -> io::Result<()> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    let entry = format!("{}={}", key, value);
+    let mut found = false;
+    let mut lines: Vec<&str> = contents
+        .lines()
+        .map(|line| match line.split_once('=') {
+            Some((k, _)) if k == key => {
+                found = true;
+                entry.as_str()
+            }
+            _ => line,
+        })
+        .collect();
+    if !found {
+        lines.push(&entry);
+    }
+
+    std::fs::write(path, lines.join("\n") + "\n")
+}
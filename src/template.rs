@@ -0,0 +1,42 @@
+//! `{placeholder}` substitution for prompts and messages.
+
+use std::fmt::{Display, Write};
+
+/// Replaces each `{name}` in `template` with the value paired with `name` in `vars`.
+///
+/// `{{` and `}}` stand for literal braces. Anything else, like an unknown placeholder
+/// or a brace with no partner, is kept exactly as written, so rendering never fails.
+pub(crate) fn render(template: &str, vars: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(i) = rest.find(['{', '}']) {
+        out.push_str(&rest[..i]);
+        let tail = &rest[i..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+
+        let var = tail.strip_prefix('{').and_then(|t| {
+            let name = &t[..t.find('}')?];
+            let (_, value) = vars.iter().find(|(n, _)| *n == name)?;
+            Some((name.len(), value))
+        });
+        match var {
+            Some((len, value)) => {
+                let _ = write!(out, "{}", value);
+                rest = &tail[len + 2..];
+            }
+            None => {
+                out.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,124 @@
+//! Terminal-specific behavior, enabled by the `tty` feature.
+
+use std::io::{stderr, stdout, IsTerminal, Write};
+
+/// Detects the width of the terminal stdout (or with `to_stderr`, stderr) is attached
+/// to, in columns
+pub(crate) fn width(to_stderr: bool) -> Option<usize> {
+    let terminal = match to_stderr {
+        true => stderr().is_terminal(),
+        false => stdout().is_terminal(),
+    };
+    if !terminal {
+        return None;
+    }
+
+    ioctl_width(if to_stderr { 2 } else { 1 })
+        .or_else(|| std::env::var("COLUMNS").ok()?.parse().ok())
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn ioctl_width(fd: std::os::raw::c_int) -> Option<usize> {
+    use std::os::raw::{c_int, c_ulong, c_ushort};
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Winsize {
+        ws_row: c_ushort,
+        ws_col: c_ushort,
+        ws_xpixel: c_ushort,
+        ws_ypixel: c_ushort,
+    }
+
+    extern "C" {
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+    }
+
+    #[cfg(target_os = "linux")]
+    const TIOCGWINSZ: c_ulong = 0x5413;
+    #[cfg(target_os = "macos")]
+    const TIOCGWINSZ: c_ulong = 0x4008_7468;
+
+    let mut size = Winsize::default();
+    // SAFETY: TIOCGWINSZ only writes a `winsize` through the pointer, which is valid
+    let ok = unsafe { ioctl(fd, TIOCGWINSZ, &mut size as *mut Winsize) } == 0;
+    (ok && size.ws_col > 0).then_some(size.ws_col as usize)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn ioctl_width(_fd: i32) -> Option<usize> {
+    None
+}
+
+/// Word-wraps `text` so no line is longer than `width` characters (unless a single
+/// word is). Existing line breaks and any trailing whitespace are kept as-is.
+pub(crate) fn wrap(text: &str, width: usize) -> String {
+    let body = text.trim_end_matches([' ', '\t']);
+    let trailing = &text[body.len()..];
+
+    let lines: Vec<String> = body
+        .split('\n')
+        .map(|line| {
+            let mut wrapped = String::new();
+            let mut len = 0;
+            for word in line.split_whitespace() {
+                let wlen = word.chars().count();
+                if len > 0 && len + 1 + wlen > width {
+                    wrapped.push('\n');
+                    len = 0;
+                } else if len > 0 {
+                    wrapped.push(' ');
+                    len += 1;
+                }
+                wrapped.push_str(word);
+                len += wlen;
+            }
+            wrapped
+        })
+        .collect();
+
+    lines.join("\n") + trailing
+}
+
+/// Runs `work`, animating a spinner on the current line of stdout (or with `to_stderr`,
+/// stderr) until it returns. Nothing is drawn when that isn't a terminal.
+pub(crate) fn spin<R, F>(to_stderr: bool, work: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let terminal = match to_stderr {
+        true => stderr().is_terminal(),
+        false => stdout().is_terminal(),
+    };
+    if !terminal {
+        return work();
+    }
+
+    let done = Arc::new(AtomicBool::new(false));
+    let spinner = {
+        let done = Arc::clone(&done);
+        std::thread::spawn(move || {
+            let mut out: Box<dyn Write> = match to_stderr {
+                true => Box::new(stderr()),
+                false => Box::new(stdout()),
+            };
+            for frame in ['|', '/', '-', '\\'].iter().cycle() {
+                if done.load(Ordering::Relaxed) {
+                    break;
+                }
+                let _ = write!(out, "\r{}", frame).and_then(|_| out.flush());
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            let _ = write!(out, "\r \r").and_then(|_| out.flush());
+        })
+    };
+
+    let result = work();
+    done.store(true, Ordering::Relaxed);
+    let _ = spinner.join();
+    result
+}
@@ -0,0 +1,569 @@
+//! Checks the doc examples leave out to stay short, mostly of exactly what the user sees
+
+use super::*;
+use std::borrow::Cow;
+use std::io::{self, Cursor};
+use std::num::NonZeroU32;
+
+/// A writer that can still be read after a clone of it is handed to an Input
+#[derive(Clone, Default)]
+struct Capture(Rc<RefCell<Vec<u8>>>);
+
+impl Capture {
+    /// Takes everything written so far
+    fn take(&self) -> String {
+        String::from_utf8(self.0.take()).unwrap()
+    }
+}
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn err_with_replaces_err_msg() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .err_msg("Invalid input")
+        .err_with(|s| format!("'{}' is not a valid integer", s))
+        .with_reader(&b" foo \n12\n"[..])
+        .with_writer(out.clone());
+    let n: i32 = input.prompt("Count: ").wait();
+
+    assert_eq!(n, 12);
+    assert_eq!(out.take(), "Count: 'foo' is not a valid integer\nCount: ");
+}
+
+#[test]
+fn example_follows_invalid_input() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .example("8080")
+        .with_reader(&b"http\n8080\n"[..])
+        .with_writer(out.clone());
+    let port: u16 = input.prompt("Port: ").wait();
+
+    assert_eq!(port, 8080);
+    assert_eq!(out.take(), "Port: Invalid. Example: 8080\nPort: ");
+}
+
+#[test]
+fn menu_retry_prompt_replaces_prompt_after_invalid_choice() {
+    let out = Capture::default();
+    let color = Input::new()
+        .menu_retry_prompt("Choice: ")
+        .with_reader(&b"3\n2\n"[..])
+        .with_writer(out.clone())
+        .wait_opts(&["Red", "Green"], "Which color do you like best? ");
+
+    assert_eq!(color, "Green");
+    assert_eq!(
+        out.take(),
+        "1. Red\n2. Green\nWhich color do you like best? \
+         Please enter a number within the bounds 1..=2\n\
+         1. Red\n2. Green\nChoice: "
+    );
+}
+
+#[test]
+fn prompt_decorator_output_is_printed() {
+    let out = Capture::default();
+    let name: String = Input::new()
+        .prompt_decorator(|p| format!("» {}", p))
+        .with_reader(&b"Ada\n"[..])
+        .with_writer(out.clone())
+        .prompt("Enter your name: ")
+        .wait();
+
+    assert_eq!(name, "Ada");
+    assert_eq!(out.take(), "» Enter your name: ");
+}
+
+#[test]
+fn auto_suffix_ends_prompt_with_one_space() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .auto_suffix(true)
+        .with_reader(&b"Ada\nLovelace\n"[..])
+        .with_writer(out.clone());
+
+    let _: String = input.prompt("First name:").wait();
+    let _: String = input.prompt("Last name:   ").wait();
+    assert_eq!(out.take(), "First name: Last name: ");
+}
+
+#[test]
+fn prompt_suffix_is_added_once() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .prompt_suffix(": ")
+        .with_reader(&b"Ada\n36\n"[..])
+        .with_writer(out.clone());
+
+    let _: String = input.prompt("Name").wait();
+    let _: u32 = input.prompt("Age: ").wait();
+    assert_eq!(out.take(), "Name: Age: ");
+}
+
+#[test]
+fn max_len_live_checks_piped_input_once_entered() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .max_len_live(8)
+        .with_writer(out.clone())
+        .chain_source(Box::new(Cursor::new("much too long\nshort\n")));
+    let nick: String = input.prompt("Nickname: ").wait();
+
+    assert_eq!(nick, "short");
+    assert!(out.take().contains("Too long (13/8)"));
+}
+
+#[test]
+fn sticky_default_shows_last_answer() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .sticky_default(true)
+        .with_reader(&b"kg\n\n"[..])
+        .with_writer(out.clone());
+
+    let first: String = input.prompt("Unit: ").wait();
+    // The user just presses Enter here, so this is the same as `first`
+    let second: String = input.prompt("Unit: ").wait();
+
+    assert_eq!((first.as_str(), second.as_str()), ("kg", "kg"));
+    assert_eq!(out.take(), "Unit: Unit [kg]: ");
+}
+
+#[test]
+fn help_keyword_prints_help_text() {
+    let out = Capture::default();
+    let date: String = Input::new()
+        .help("Dates are written as YYYY-MM-DD", "?")
+        .with_reader(&b"?\n2024-05-01\n"[..])
+        .with_writer(out.clone())
+        .prompt("Start date (? for help): ")
+        .wait();
+
+    assert_eq!(date, "2024-05-01");
+    assert_eq!(
+        out.take(),
+        "Start date (? for help): Dates are written as YYYY-MM-DD\n\
+         Start date (? for help): "
+    );
+}
+
+#[test]
+fn with_writer_receives_prompts_and_errors() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .err_msg("Not a number")
+        .with_reader(&b"many\n3\n"[..])
+        .with_writer(out.clone());
+    let n: u32 = input.prompt("How many? ").wait();
+
+    assert_eq!(n, 3);
+    assert_eq!(out.take(), "How many? Not a number\nHow many? ");
+}
+
+#[test]
+fn auto_select_single_announces_choice() {
+    let out = Capture::default();
+    let input = Input::new()
+        .auto_select_single(true)
+        .with_writer(out.clone());
+
+    assert_eq!(input.wait_opts(&["eth0"], "Interface: "), "eth0");
+    assert_eq!(out.take(), "Selected eth0 (the only option)\n");
+}
+
+#[test]
+fn suggestions_name_close_option() {
+    let out = Capture::default();
+    let input = Input::new()
+        .suggestions(true)
+        .with_writer(out.clone())
+        .chain_source(Box::new(Cursor::new("Secnd\n2\n")));
+
+    assert_eq!(input.wait_opts(&["First", "Second"], "Pick: "), "Second");
+    assert!(String::from_utf8(out.0.take())
+        .unwrap()
+        .contains("Did you mean 'Second'?"));
+}
+
+#[test]
+fn page_size_shows_one_page_at_a_time() {
+    let out = Capture::default();
+    let input = Input::new()
+        .page_size(2)
+        .with_reader(&b"n\nn\n5\n"[..])
+        .with_writer(out.clone());
+    let planets = ["Mercury", "Venus", "Earth", "Mars", "Jupiter"];
+
+    assert_eq!(
+        input.wait_opts_indexed(&planets, "Planet: "),
+        (4, "Jupiter")
+    );
+    assert_eq!(
+        out.take(),
+        "1. Mercury\n2. Venus\nPage 1 of 3 (n: next, p: previous)\nPlanet: \
+         3. Earth\n4. Mars\nPage 2 of 3 (n: next, p: previous)\nPlanet: \
+         5. Jupiter\nPage 3 of 3 (n: next, p: previous)\nPlanet: "
+    );
+}
+
+#[test]
+fn list_skip_invalid_prints_no_error() {
+    let out = Capture::default();
+    let numbers: Vec<i32> = Input::new()
+        .list_skip_invalid(true)
+        .with_reader(&b"1\ntwo\n2\n\n"[..])
+        .with_writer(out.clone())
+        .wait_list("Numbers:\n");
+
+    assert_eq!(numbers, [1, 2]);
+    assert_eq!(out.take(), "Numbers:\n");
+}
+
+#[test]
+fn force_color_colors_prompt_off_terminal() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .force_color(true)
+        .prompt_color(Color::Cyan)
+        .with_reader(&b"7\n"[..])
+        .with_writer(out.clone());
+    let n: u32 = input.prompt("Number: ").wait();
+
+    assert_eq!(n, 7);
+    assert_eq!(out.take(), "\x1b[36mNumber: \x1b[0m");
+}
+
+#[test]
+fn echo_repeats_accepted_answers() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .echo(true)
+        .with_reader(&b"Alice\n2\n"[..])
+        .with_writer(out.clone());
+    let name: String = input.prompt("Enter your name: ").wait();
+    let team = input.wait_opts(&["Red", "Blue"], "Team: ");
+
+    assert_eq!((name.as_str(), team), ("Alice", "Blue"));
+    assert_eq!(
+        out.take(),
+        "Enter your name: \u{2713} Enter your name: Alice\n\
+         1. Red\n2. Blue\nTeam: \u{2713} Team: Blue\n"
+    );
+}
+
+#[test]
+fn prompt_once_shows_prompt_until_reset() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .prompt_once(true)
+        .with_reader(&b"1\n2\n3\n4\n"[..])
+        .with_writer(out.clone());
+    input.prompt("Values, one per line: ");
+
+    let values: Vec<i32> = (0..3).map(|_| input.wait()).collect();
+    assert_eq!(values, [1, 2, 3]);
+    assert_eq!(out.take(), "Values, one per line: ");
+
+    input.reset();
+    assert_eq!(input.prompt("One more: ").wait::<i32>(), 4);
+    assert_eq!(out.take(), "One more: ");
+}
+
+#[test]
+fn reprompt_off_skips_prompt_after_error() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .err_msg("Not a number, try again:")
+        .with_reader(&b"ten\n10\n"[..])
+        .with_writer(out.clone());
+
+    let n: u32 = input.prompt("Count: ").wait();
+    assert_eq!(n, 10);
+    assert_eq!(out.take(), "Count: Not a number, try again:\nCount: ");
+
+    let mut input = input.reprompt(false).with_reader(&b"ten\n10\n"[..]);
+    let n: u32 = input.prompt("Count: ").wait();
+    assert_eq!(n, 10);
+    assert_eq!(out.take(), "Count: Not a number, try again:\n");
+}
+
+#[test]
+fn secret_answers_are_not_sticky() {
+    let out = Capture::default();
+    let mut input = Input::new()
+        .secret(true)
+        .sticky_default(true)
+        .chain_source(Box::new(Cursor::new("hunter2\nswordfish\n")))
+        .with_writer(out.clone());
+    let password: String = input.prompt("Password: ").wait();
+
+    assert_eq!(password, "hunter2");
+    assert_eq!(out.take(), "Password: \n");
+
+    // Asking again doesn't offer the last password as the default
+    let again: String = input.wait();
+    assert_eq!(again, "swordfish");
+    assert_eq!(out.take(), "Password: \n");
+}
+
+#[test]
+fn wait_nonzero_explains_zero() {
+    let out = Capture::default();
+    let divisor: NonZeroU32 = Input::new()
+        .err_msg("Not a number; please retry")
+        .with_reader(&b"000\nfive\n5\n"[..])
+        .with_writer(out.clone())
+        .wait_nonzero("Divide by: ");
+
+    assert_eq!(divisor.get(), 5);
+    assert_eq!(
+        out.take(),
+        "Divide by: Value must not be zero\n\
+         Divide by: Not a number; please retry\n\
+         Divide by: "
+    );
+}
+
+#[test]
+fn warn_privileged_warns_below_1024() {
+    let out = Capture::default();
+    let input = Input::new()
+        .warn_privileged(true)
+        .with_writer(out.clone())
+        .chain_source(Box::new(Cursor::new("80\n")));
+
+    assert_eq!(input.wait_port("Port: "), 80);
+    assert!(out.take().contains("port 80 is privileged"));
+}
+
+#[test]
+fn wait_check_prints_check_error() {
+    let out = Capture::default();
+    let age: u32 = Input::new()
+        .err_msg("That wasn't a number")
+        .with_reader(&b"old\n16\n21\n"[..])
+        .with_writer(out.clone())
+        .wait_check("Enter your age: ", |age| {
+            if *age >= 18 {
+                Ok(())
+            } else {
+                Err(format!("You must be 18 or older, not {}", age))
+            }
+        });
+
+    assert_eq!(age, 21);
+    assert_eq!(
+        out.take(),
+        "Enter your age: That wasn't a number\n\
+         Enter your age: You must be 18 or older, not 16\n\
+         Enter your age: "
+    );
+}
+
+#[test]
+fn wait_list_reports_invalid_entries() {
+    let out = Capture::default();
+    let numbers: Vec<i32> = Input::new()
+        .err_msg("Not a number; retype it")
+        .with_reader(&b"1\ntwo\n2\n3\n\n"[..])
+        .with_writer(out.clone())
+        .wait_list("Enter numbers, one per line:\n");
+
+    assert_eq!(numbers, [1, 2, 3]);
+    assert_eq!(
+        out.take(),
+        "Enter numbers, one per line:\nNot a number; retype it\n"
+    );
+}
+
+#[test]
+fn wait_allowed_suggests_close_word() {
+    let out = Capture::default();
+    let cmd = Input::new()
+        .err_msg("Unknown command")
+        .with_reader(&b"stat\nSTOP\n"[..])
+        .with_writer(out.clone())
+        .wait_allowed(&["start", "stop", "status"], "Command: ");
+
+    assert_eq!(cmd, "stop");
+    assert_eq!(out.take(), "Command: Did you mean 'start'?\nCommand: ");
+}
+
+#[test]
+fn render_opts_matches_wait_opts_menu() {
+    let opts = ["New game", "Load game", "Quit"];
+    let menu = Input::new().render_opts(&opts);
+    assert_eq!(menu, "1. New game\n2. Load game\n3. Quit\n");
+
+    // The same menu wait_opts shows before its prompt
+    let out = Capture::default();
+    let input = Input::new()
+        .with_reader(&b"1\n"[..])
+        .with_writer(out.clone());
+    input.wait_opts(&opts, "Choice: ");
+    assert_eq!(out.take(), menu + "Choice: ");
+}
+
+#[test]
+fn choose_prints_err_msg() {
+    let out = Capture::default();
+    let input = Input::new()
+        .err_msg("Please answer yes or no")
+        .with_writer(out.clone())
+        .chain_source(Box::new(Cursor::new("maybe\nYes\n")));
+
+    assert!(input.choose("Continue?"));
+    assert!(String::from_utf8(out.0.take())
+        .unwrap()
+        .contains("Please answer yes or no"));
+}
+
+#[test]
+fn choose_opts_shows_default_and_err_msg() {
+    let out = Capture::default();
+    let input = Input::new()
+        .err_msg("Bitte j oder n eingeben")
+        .with_reader(&b"y\n\nN\n"[..])
+        .with_writer(out.clone());
+
+    assert!(input.choose_opts("Fortfahren?", &['j'], &['n'], Some(true)));
+    assert!(!input.choose_opts("Fortfahren?", &['j'], &['n'], Some(true)));
+    assert_eq!(
+        out.take(),
+        "Fortfahren? [J/n] Bitte j oder n eingeben\n\
+         Fortfahren? [J/n] \
+         Fortfahren? [J/n] "
+    );
+}
+
+#[test]
+fn from_config_sets_prompt_and_err_msg() {
+    let cfg = InputConfig {
+        prompt: Some("Enter your age: ".into()),
+        err_msg: Some("Unexpected input; please retry".into()),
+        ..Default::default()
+    };
+
+    let out = Capture::default();
+    let age: u32 = Input::from_config(cfg)
+        .with_reader(&b"old\n42\n"[..])
+        .with_writer(out.clone())
+        .wait();
+
+    assert_eq!(age, 42);
+    assert_eq!(
+        out.take(),
+        "Enter your age: Unexpected input; please retry\nEnter your age: "
+    );
+}
+
+/// A parsed type that isn't `'static`, like ones that borrow from elsewhere
+struct Word<'a>(Cow<'a, str>);
+
+impl std::str::FromStr for Word<'_> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Ok(Word(Cow::Owned(s.to_owned())))
+    }
+}
+
+#[test]
+fn reads_of_borrowed_types_skip_checks_for_other_types() {
+    fn words<'a>(input: &Input, _: &'a str) -> (Word<'a>, Option<Word<'a>>) {
+        (input.wait(), input.read())
+    }
+
+    let input = Input::new()
+        .validate(|n: &u32| *n > 10)
+        .min(5u32)
+        .with_reader(&b"3\n4\n"[..])
+        .with_writer(io::sink());
+    let (first, second) = words(&input, "");
+
+    assert_eq!(first.0, "3");
+    assert_eq!(second.map(|w| w.0), Some("4".into()));
+}
+
+#[test]
+fn reads_after_wait_or_quit_ask_past_a_soft_quit() {
+    let input = Input::new()
+        .quit("q")
+        .soft_quit(true)
+        .with_reader(&b"q\nq\n2\nq\n7\n"[..])
+        .with_writer(io::sink());
+
+    assert_eq!(input.wait_or_quit::<u32>(), Err(Quit));
+    assert_eq!(input.wait_opts(&["Red", "Blue"], "Color: "), "Blue");
+    assert_eq!(input.wait::<u32>(), 7);
+}
+
+#[test]
+fn sticky_default_keeps_only_confirmed_values() {
+    let input = Input::new()
+        .sticky_default(true)
+        .with_reader(&b"5\n6\n\n7\n7\n\n"[..])
+        .with_writer(io::sink());
+
+    // The 6 didn't match, so the Enter after it has no default to fall back on
+    assert_eq!(input.wait_confirmed::<u32>("PIN: ", "Again: "), 7);
+    assert_eq!(input.wait::<u32>(), 7);
+}
+
+#[test]
+fn sticky_default_skips_yes_no_answers() {
+    let input = Input::new()
+        .sticky_default(true)
+        .with_reader(&b"3\ny\n\n"[..])
+        .with_writer(io::sink());
+
+    assert_eq!(input.wait::<u32>(), 3);
+    assert!(input.choose("Again?"));
+    assert_eq!(input.wait::<u32>(), 3);
+}
+
+#[test]
+fn helpers_apply_min_max_and_validate() {
+    let out = Capture::default();
+    let input = Input::new()
+        .min(1u32)
+        .validate(|n: &u32| n.is_multiple_of(2))
+        .with_reader(&b"0\n2\n3\n4\n\n"[..])
+        .with_writer(out.clone());
+
+    assert_eq!(input.wait_check("Even: ", |_: &u32| Ok(())), 2);
+    assert_eq!(out.take(), "Even: 0 must be at least 1\nEven: ");
+    assert_eq!(input.wait_list::<u32>("More:"), [4]);
+}
+
+#[test]
+fn try_read_now_checks_lines_like_read() {
+    let out = Capture::default();
+    let input = Input::new()
+        .max_len(3)
+        .validate(|n: &u32| *n > 0)
+        .record_history(true)
+        .scripted(vec!["12345".into(), "0".into(), "x".into(), "42".into()])
+        .with_reader(&b""[..])
+        .with_writer(out.clone());
+
+    assert_eq!(input.try_read_now::<u32>(), None);
+    assert_eq!(
+        out.take(),
+        "Too long (5/3); please enter at most 3 characters\n"
+    );
+    assert_eq!(input.try_read_now::<u32>(), None);
+    assert!(matches!(input.try_read_now::<u32>(), Some(Err(_))));
+    assert_eq!(input.try_read_now::<u32>(), Some(Ok(42)));
+    assert_eq!(*input.history(), ["42"]);
+}